@@ -1,47 +1,89 @@
 use crate::{
-    execute::{execute_burn, execute_mint},
-    msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg},
-    query::query_config,
-    state::{Config, CONFIG},
+    execute::{
+        execute_add_bridged_denom, execute_approve_withdrawal, execute_attest_supply, execute_burn,
+        execute_burn_intent, execute_burn_to_evm, execute_cancel_queued, execute_cancel_withdrawal,
+        execute_claim_pending, execute_claim_withdrawal, execute_deposit_for_intents,
+        execute_execute_queued, execute_grant_role, execute_migrate_to_successor, execute_mint,
+        execute_mint_batch, execute_pause, execute_queue_change, execute_register_asset,
+        execute_register_escrow_query, execute_return_pending, execute_revoke_role,
+        execute_set_amount_bounds, execute_set_attestation_config, execute_set_bridge_status,
+        execute_set_exchange_rate_source, execute_set_gmp_config, execute_set_routing_policy,
+        execute_set_withdrawal_queue, execute_sweep_surplus, execute_unpause,
+        execute_update_exchange_rate, execute_withdraw_intent_deposit, log_admin_action,
+        migrate_legacy_config, register_asset,
+    },
+    msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, SudoMsg},
+    query::{
+        query_admin_log, query_asset, query_burn_intent_digest, query_claim_message, query_config,
+        query_convert_to_steth, query_exchange_rate, query_health, query_idempotency_key,
+        query_intent_account, query_pending_claims, query_proof_of_reserve, query_queued_changes,
+        query_referral_stats, query_roles, query_surplus, query_withdrawals,
+    },
+    state::{
+        Config, ASSETS, CANONICAL_DENOMS, CONFIG, ESCROW_QUERIES, ESCROW_QUERY_IDS, LEGACY_CONFIG,
+        OUTBOUND_TRANSFERS, PENDING_ASSET, PENDING_ESCROW_QUERY, PENDING_TRANSFER,
+    },
+    sudo::{
+        sudo_health_check, sudo_kv_query_result, sudo_transfer_acknowledged, sudo_transfer_failed,
+    },
     ContractError, ContractResult,
 };
-use cosmwasm_std::{attr, Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response, SubMsg};
+use cosmwasm_std::{
+    attr, from_json, to_json_string, Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response,
+    StdError,
+};
 use cw2::set_contract_version;
 use neutron_sdk::{
-    bindings::{msg::NeutronMsg, query::NeutronQuery},
+    bindings::{
+        msg::{MsgIbcTransferResponse, MsgRegisterInterchainQueryResponse, NeutronMsg},
+        query::NeutronQuery,
+    },
     query::token_factory::query_full_denom,
 };
 
 pub const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
 pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
-const CREATE_DENOM_REPLY_ID: u64 = 1;
+pub(crate) const CREATE_DENOM_REPLY_ID: u64 = 1;
+pub(crate) const REGISTER_ESCROW_QUERY_REPLY_ID: u64 = 2;
+pub(crate) const BURN_TO_EVM_REPLY_ID: u64 = 3;
 
 #[cfg_attr(not(feature = "library"), cosmwasm_std::entry_point)]
 pub fn instantiate(
     deps: DepsMut<NeutronQuery>,
-    _env: Env,
-    _info: MessageInfo,
+    env: Env,
+    info: MessageInfo,
     msg: InstantiateMsg,
 ) -> ContractResult<Response<NeutronMsg>> {
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
     msg.validate()?;
     let config = Config {
-        bridged_denom: msg.bridged_denom,
-        // we save here just a plain subdenom,
-        // which will be updated to a full denom later in the reply handler
-        canonical_denom: msg.canonical_subdenom,
+        owner: msg.owner.map_or(Ok(info.sender.clone()), |addr| {
+            deps.api.addr_validate(&addr)
+        })?,
     };
     CONFIG.save(deps.storage, &config)?;
+    log_admin_action(
+        deps.storage,
+        &env,
+        &info.sender,
+        "instantiate",
+        None,
+        Some(to_json_string(&config)?),
+    )?;
 
-    let create_denom_msg = NeutronMsg::submit_create_denom(&config.canonical_denom);
-    let create_denom_submsg = SubMsg::reply_on_success(create_denom_msg, CREATE_DENOM_REPLY_ID);
+    let create_denom_submsg = register_asset(
+        deps.storage,
+        &msg.canonical_subdenom,
+        &[msg.bridged_denom.clone()],
+    )?;
     Ok(Response::new()
         .add_submessage(create_denom_submsg)
         .add_attributes([
-            attr("bridged_denom", config.bridged_denom),
-            attr("canonical_subdenom", config.canonical_denom),
+            attr("bridged_denom", msg.bridged_denom),
+            attr("canonical_subdenom", msg.canonical_subdenom),
+            attr("owner", config.owner),
         ]))
 }
 
@@ -53,42 +95,308 @@ pub fn execute(
     msg: ExecuteMsg,
 ) -> ContractResult<Response<NeutronMsg>> {
     match msg {
-        ExecuteMsg::Mint { receiver } => execute_mint(deps, env, info, receiver),
-        ExecuteMsg::Burn { receiver } => execute_burn(deps, env, info, receiver),
+        ExecuteMsg::Mint {
+            receiver,
+            idempotency_key,
+            source_chain,
+            source_address,
+            evm_sender,
+            ibc_hooks_origin,
+            referral,
+            memo,
+        } => execute_mint(
+            deps,
+            env,
+            info,
+            receiver,
+            idempotency_key,
+            source_chain,
+            source_address,
+            evm_sender,
+            ibc_hooks_origin,
+            referral,
+            memo,
+        ),
+        ExecuteMsg::MintBatch { outputs } => execute_mint_batch(deps, env, info, outputs),
+        ExecuteMsg::Burn {
+            receiver,
+            bridged_denom,
+            referral,
+            memo,
+        } => execute_burn(deps, env, info, receiver, bridged_denom, referral, memo),
+        ExecuteMsg::BurnToEvm {
+            evm_receiver,
+            destination_chain,
+            gas_fee,
+        } => execute_burn_to_evm(deps, env, info, evm_receiver, destination_chain, gas_fee),
+        ExecuteMsg::DepositForIntents { pubkey } => {
+            execute_deposit_for_intents(deps, env, info, pubkey)
+        }
+        ExecuteMsg::WithdrawIntentDeposit { denom, amount } => {
+            execute_withdraw_intent_deposit(deps, env, info, denom, amount)
+        }
+        ExecuteMsg::ExecuteBurnIntent {
+            intent,
+            signature,
+            relayer_fee,
+        } => execute_burn_intent(deps, env, info, intent, signature, relayer_fee),
+        ExecuteMsg::ClaimPending {
+            evm_address,
+            bridged_denom,
+            receiver,
+            signature,
+        } => execute_claim_pending(
+            deps,
+            env,
+            info,
+            evm_address,
+            bridged_denom,
+            receiver,
+            signature,
+        ),
+        ExecuteMsg::ReturnPending {
+            evm_address,
+            bridged_denom,
+        } => execute_return_pending(deps, env, info, evm_address, bridged_denom),
+        ExecuteMsg::SetGmpConfig {
+            sender,
+            channel,
+            gateway,
+            gas_service,
+            bridged_denoms,
+        } => execute_set_gmp_config(
+            deps,
+            env,
+            info,
+            sender,
+            channel,
+            gateway,
+            gas_service,
+            bridged_denoms,
+        ),
+        ExecuteMsg::AttestSupply {} => execute_attest_supply(deps, env, info),
+        ExecuteMsg::SetAttestationConfig {
+            canonical_subdenom,
+            destination_chain,
+            destination_address,
+            min_interval,
+        } => execute_set_attestation_config(
+            deps,
+            env,
+            info,
+            canonical_subdenom,
+            destination_chain,
+            destination_address,
+            min_interval,
+        ),
+        ExecuteMsg::UpdateExchangeRate {
+            source_chain,
+            source_address,
+            rate,
+            block_number,
+            block_timestamp,
+        } => execute_update_exchange_rate(
+            deps,
+            env,
+            info,
+            source_chain,
+            source_address,
+            rate,
+            block_number,
+            block_timestamp,
+        ),
+        ExecuteMsg::SetExchangeRateSource {
+            source_chain,
+            source_address,
+        } => execute_set_exchange_rate_source(deps, env, info, source_chain, source_address),
+        ExecuteMsg::RegisterEscrowQuery {
+            bridged_denom,
+            connection_id,
+            escrow_address,
+            remote_denom,
+            update_period,
+        } => execute_register_escrow_query(
+            deps,
+            env,
+            info,
+            bridged_denom,
+            connection_id,
+            escrow_address,
+            remote_denom,
+            update_period,
+        ),
+        ExecuteMsg::RegisterAsset {
+            canonical_subdenom,
+            bridged_denoms,
+        } => execute_register_asset(deps, env, info, canonical_subdenom, bridged_denoms),
+        ExecuteMsg::AddBridgedDenom {
+            canonical_subdenom,
+            bridged_denom,
+        } => execute_add_bridged_denom(deps, env, info, canonical_subdenom, bridged_denom),
+        ExecuteMsg::SetBridgeStatus {
+            bridged_denom,
+            status,
+        } => execute_set_bridge_status(deps, env, info, bridged_denom, status),
+        ExecuteMsg::SetAmountBounds {
+            bridged_denom,
+            min_amount,
+            max_amount,
+        } => execute_set_amount_bounds(deps, env, info, bridged_denom, min_amount, max_amount),
+        ExecuteMsg::SetWithdrawalQueue {
+            canonical_subdenom,
+            config,
+        } => execute_set_withdrawal_queue(deps, env, info, canonical_subdenom, config),
+        ExecuteMsg::ClaimWithdrawal { id } => execute_claim_withdrawal(deps, env, info, id),
+        ExecuteMsg::ApproveWithdrawal { id } => execute_approve_withdrawal(deps, env, info, id),
+        ExecuteMsg::CancelWithdrawal { id } => execute_cancel_withdrawal(deps, env, info, id),
+        ExecuteMsg::SetRoutingPolicy {
+            canonical_subdenom,
+            routing_policy,
+        } => execute_set_routing_policy(deps, env, info, canonical_subdenom, routing_policy),
+        ExecuteMsg::GrantRole { role, address } => {
+            execute_grant_role(deps, env, info, role, address)
+        }
+        ExecuteMsg::RevokeRole { role, address } => {
+            execute_revoke_role(deps, env, info, role, address)
+        }
+        ExecuteMsg::Pause {} => execute_pause(deps, env, info),
+        ExecuteMsg::Unpause {} => execute_unpause(deps, env, info),
+        ExecuteMsg::QueueChange { change } => execute_queue_change(deps, env, info, change),
+        ExecuteMsg::ExecuteQueued { id } => execute_execute_queued(deps, env, info, id),
+        ExecuteMsg::CancelQueued { id } => execute_cancel_queued(deps, env, info, id),
+        ExecuteMsg::SweepSurplus { denom, to } => execute_sweep_surplus(deps, env, info, denom, to),
+        ExecuteMsg::MigrateToSuccessor { successor } => {
+            execute_migrate_to_successor(deps, env, info, successor)
+        }
     }
 }
 
 #[cfg_attr(not(feature = "library"), cosmwasm_std::entry_point)]
-pub fn query(deps: Deps<NeutronQuery>, _env: Env, msg: QueryMsg) -> ContractResult<Binary> {
+pub fn query(deps: Deps<NeutronQuery>, env: Env, msg: QueryMsg) -> ContractResult<Binary> {
     match msg {
         QueryMsg::Config {} => query_config(deps),
+        QueryMsg::Roles {} => query_roles(deps),
+        QueryMsg::AdminLog { start_after, limit } => query_admin_log(deps, start_after, limit),
+        QueryMsg::QueuedChanges { start_after, limit } => {
+            query_queued_changes(deps, start_after, limit)
+        }
+        QueryMsg::Asset { canonical_subdenom } => query_asset(deps, canonical_subdenom),
+        QueryMsg::ReferralStats { referral } => query_referral_stats(deps, referral),
+        QueryMsg::Surplus {} => query_surplus(deps, env),
+        QueryMsg::Withdrawals {
+            receiver,
+            start_after,
+            limit,
+        } => query_withdrawals(deps, receiver, start_after, limit),
+        QueryMsg::PendingClaims { evm_address } => query_pending_claims(deps, evm_address),
+        QueryMsg::ClaimMessage {
+            evm_address,
+            bridged_denom,
+            receiver,
+        } => query_claim_message(deps, env, evm_address, bridged_denom, receiver),
+        QueryMsg::ExchangeRate {} => query_exchange_rate(deps),
+        QueryMsg::Health {} => query_health(deps),
+        QueryMsg::ProofOfReserve {} => query_proof_of_reserve(deps, env),
+        QueryMsg::ConvertToStEth { amount } => query_convert_to_steth(deps, amount),
+        QueryMsg::IdempotencyKey { sender, key } => query_idempotency_key(deps, env, sender, key),
+        QueryMsg::IntentAccount { address } => query_intent_account(deps, address),
+        QueryMsg::BurnIntentDigest { intent } => query_burn_intent_digest(env, intent),
+    }
+}
+
+#[cfg_attr(not(feature = "library"), cosmwasm_std::entry_point)]
+pub fn sudo(
+    deps: DepsMut<NeutronQuery>,
+    env: Env,
+    msg: SudoMsg,
+) -> ContractResult<Response<NeutronMsg>> {
+    match msg {
+        SudoMsg::HealthCheck {} => sudo_health_check(deps, env),
+        SudoMsg::KVQueryResult { query_id } => sudo_kv_query_result(deps, query_id),
+        SudoMsg::Response { request, data: _ } => sudo_transfer_acknowledged(deps, request),
+        SudoMsg::Error { request, details } => sudo_transfer_failed(deps, request, &details),
+        SudoMsg::Timeout { request } => sudo_transfer_failed(deps, request, "timeout"),
     }
 }
 
 #[cfg_attr(not(feature = "library"), cosmwasm_std::entry_point)]
 pub fn migrate(
-    _deps: DepsMut<NeutronQuery>,
-    _env: Env,
-    _msg: MigrateMsg,
+    mut deps: DepsMut<NeutronQuery>,
+    env: Env,
+    msg: MigrateMsg,
 ) -> ContractResult<Response> {
-    Ok(Response::new())
+    // config of the current layout fails to load as a legacy one
+    let response = match LEGACY_CONFIG.load(deps.storage) {
+        Ok(legacy) => {
+            let owner = msg.owner.ok_or(ContractError::MigrationOwnerRequired {})?;
+            let owner = deps.api.addr_validate(&owner)?;
+            migrate_legacy_config(deps.branch(), &env, legacy, owner)?
+        }
+        Err(_) if msg.owner.is_some() => return Err(ContractError::UnexpectedMigrationOwner {}),
+        Err(_) => Response::new(),
+    };
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    Ok(response)
 }
 
 #[cfg_attr(not(feature = "library"), cosmwasm_std::entry_point)]
 pub fn reply(deps: DepsMut<NeutronQuery>, env: Env, msg: Reply) -> ContractResult<Response> {
     match msg.id {
         CREATE_DENOM_REPLY_ID => {
-            // at this point, `config.canonical_denom` stores just a subdenom
-            let mut config = CONFIG.load(deps.storage)?;
+            let subdenom = PENDING_ASSET.load(deps.storage)?;
+            PENDING_ASSET.remove(deps.storage);
 
-            let full_denom =
-                query_full_denom(deps.as_ref(), env.contract.address, config.canonical_denom)?;
+            // at this point, `asset.canonical_denom` stores just a subdenom
+            let mut asset = ASSETS.load(deps.storage, &subdenom)?;
+
+            let full_denom = query_full_denom(deps.as_ref(), env.contract.address, &subdenom)?;
 
             // but we replace it with a full denom at this step, like it should be
-            config.canonical_denom = full_denom.denom;
+            CANONICAL_DENOMS.remove(deps.storage, &asset.canonical_denom);
+            asset.canonical_denom = full_denom.denom;
+            CANONICAL_DENOMS.save(deps.storage, &asset.canonical_denom, &subdenom)?;
+
+            ASSETS.save(deps.storage, &subdenom, &asset)?;
+            Ok(Response::new().add_attribute("canonical_denom", asset.canonical_denom))
+        }
+        REGISTER_ESCROW_QUERY_REPLY_ID => {
+            let bridged_denom = PENDING_ESCROW_QUERY.load(deps.storage)?;
+            PENDING_ESCROW_QUERY.remove(deps.storage);
+
+            let data = msg
+                .result
+                .into_result()
+                .map_err(StdError::generic_err)?
+                .data
+                .ok_or(ContractError::EmptyReplyData { id: msg.id })?;
+            let query_id = from_json::<MsgRegisterInterchainQueryResponse>(data)?.id;
+
+            let mut escrow_query = ESCROW_QUERIES.load(deps.storage, &bridged_denom)?;
+            escrow_query.query_id = Some(query_id);
+            ESCROW_QUERIES.save(deps.storage, &bridged_denom, &escrow_query)?;
+            ESCROW_QUERY_IDS.save(deps.storage, query_id, &bridged_denom)?;
+            Ok(Response::new().add_attribute("escrow_query_id", query_id.to_string()))
+        }
+        BURN_TO_EVM_REPLY_ID => {
+            let transfer = PENDING_TRANSFER.load(deps.storage)?;
+            PENDING_TRANSFER.remove(deps.storage);
 
-            CONFIG.save(deps.storage, &config)?;
-            Ok(Response::new().add_attribute("canonical_denom", config.canonical_denom))
+            let data = msg
+                .result
+                .into_result()
+                .map_err(StdError::generic_err)?
+                .data
+                .ok_or(ContractError::EmptyReplyData { id: msg.id })?;
+            let response = from_json::<MsgIbcTransferResponse>(data)?;
+            OUTBOUND_TRANSFERS.save(
+                deps.storage,
+                (&response.channel, response.sequence_id),
+                &transfer,
+            )?;
+            Ok(Response::new().add_attributes([
+                attr("channel", response.channel),
+                attr("sequence_id", response.sequence_id.to_string()),
+            ]))
         }
         id => Err(ContractError::UnknownReplyId { id }),
     }
@@ -1,4 +1,7 @@
-use cosmwasm_std::{OverflowError, StdError};
+use crate::state::BridgeStatus;
+use cosmwasm_std::{
+    ConversionOverflowError, OverflowError, StdError, Timestamp, Uint128, VerificationError,
+};
 use neutron_sdk::NeutronError;
 use thiserror::Error;
 
@@ -13,21 +16,261 @@ pub enum ContractError {
     #[error("{0}")]
     OverflowError(#[from] OverflowError),
 
+    #[error("{0}")]
+    VerificationError(#[from] VerificationError),
+
+    #[error("{0}")]
+    ConversionOverflowError(#[from] ConversionOverflowError),
+
     #[error("denom field {kind} should not be empty")]
     EmptyDenom { kind: String },
 
     #[error("denom {denom} is not a correct IBC denom: {reason}")]
     InvalidIbcDenom { denom: String, reason: String },
 
+    #[error("asset with canonical subdenom {subdenom} is already registered")]
+    AssetAlreadyRegistered { subdenom: String },
+
+    #[error("asset with canonical subdenom {subdenom} is not registered")]
+    AssetNotRegistered { subdenom: String },
+
+    #[error("bridged denom {denom} is already registered")]
+    BridgedDenomAlreadyRegistered { denom: String },
+
+    #[error("bridged denom {denom} does not belong to asset {subdenom}")]
+    BridgedDenomNotInAsset { denom: String, subdenom: String },
+
+    #[error("insufficient reserves of {denom}: {available} available, {requested} requested")]
+    InsufficientReserves {
+        denom: String,
+        available: Uint128,
+        requested: Uint128,
+    },
+
+    #[error("bridged denom {denom} is not registered")]
+    BridgedDenomNotRegistered { denom: String },
+
+    #[error("minting against bridged denom {denom} is not allowed, bridge is {status}")]
+    MintNotAllowed { denom: String, status: BridgeStatus },
+
+    #[error("amount {amount} of {denom} is below the minimum of {min_amount}")]
+    AmountBelowMinimum {
+        denom: String,
+        amount: Uint128,
+        min_amount: Uint128,
+    },
+
+    #[error("amount {amount} of {denom} is above the maximum of {max_amount}")]
+    AmountAboveMaximum {
+        denom: String,
+        amount: Uint128,
+        max_amount: Uint128,
+    },
+
+    #[error("minimum amount {min_amount} is greater than maximum amount {max_amount}")]
+    InvalidAmountBounds {
+        min_amount: Uint128,
+        max_amount: Uint128,
+    },
+
+    #[error("bridged denom {denom} is disabled")]
+    BridgeDisabled { denom: String },
+
+    #[error("at least one bridged denom should be provided")]
+    NoBridgedDenoms {},
+
     #[error("nothing to burn: canonical funds should be provided")]
     NothingToBurn {},
 
     #[error("nothing to mint: bridged funds should be provided")]
     NothingToMint {},
 
-    #[error("extra funds have been supplied")]
+    #[error("funds of more than one asset denom have been supplied")]
     ExtraFunds {},
 
+    #[error("denom {denom} is not accepted by this message")]
+    UnexpectedFunds { denom: String },
+
+    #[error("coins of denom {denom} have been supplied more than once")]
+    DuplicateFunds { denom: String },
+
+    #[error("unauthorized")]
+    Unauthorized {},
+
+    #[error("sender is not a guardian")]
+    NotGuardian {},
+
+    #[error("sender is not a fee manager")]
+    NotFeeManager {},
+
+    #[error("sender is not a bridge manager")]
+    NotBridgeManager {},
+
+    #[error("contract is paused")]
+    Paused {},
+
+    #[error("contract has been migrated to {successor}, mint and burn are disabled")]
+    Migrated { successor: String },
+
+    #[error("invalid idempotency key: {reason}")]
+    InvalidIdempotencyKey { reason: String },
+
+    #[error("mint with idempotency key {key} has already been processed")]
+    DuplicateIdempotencyKey { key: String },
+
+    #[error("nothing to deposit: canonical funds should be provided")]
+    NothingToDeposit {},
+
+    #[error("invalid public key: expected 33 or 65 bytes")]
+    InvalidPublicKey {},
+
+    #[error("{address} has no intent account")]
+    IntentAccountNotFound { address: String },
+
+    #[error(
+        "insufficient intent deposit of {denom}: {available} available, {requested} requested"
+    )]
+    InsufficientIntentDeposit {
+        denom: String,
+        available: Uint128,
+        requested: Uint128,
+    },
+
+    #[error("invalid burn intent signature")]
+    InvalidSignature {},
+
+    #[error("invalid burn intent nonce: expected {expected}, got {actual}")]
+    InvalidNonce { expected: u64, actual: u64 },
+
+    #[error("burn intent expired at {expires_at}")]
+    IntentExpired { expires_at: Timestamp },
+
+    #[error("relayer fee {fee} exceeds maximum of {max}")]
+    RelayerFeeTooHigh { fee: Uint128, max: Uint128 },
+
+    #[error("relayer fee {fee} should be less than burned amount {amount}")]
+    RelayerFeeExceedsAmount { fee: Uint128, amount: Uint128 },
+
+    #[error("{address} is not a correct EVM address: {reason}")]
+    InvalidEvmAddress { address: String, reason: String },
+
+    #[error("Axelar GMP is not configured")]
+    GmpNotConfigured {},
+
+    #[error("no pending claim of {bridged_denom} for {evm_address}")]
+    PendingClaimNotFound {
+        evm_address: String,
+        bridged_denom: String,
+    },
+
+    #[error("asset {subdenom} has no bridged denom arriving over Axelar")]
+    NoGmpBridgedDenom { subdenom: String },
+
+    #[error("asset {subdenom} has more than one bridged denom arriving over Axelar")]
+    MultipleGmpBridgedDenoms { subdenom: String },
+
+    #[error("gas fee {fee} must be less than transferred amount {amount}")]
+    GasFeeExceedsAmount { fee: Uint128, amount: Uint128 },
+
+    #[error("supply attestation is not configured")]
+    AttestationNotConfigured {},
+
+    #[error("supply has been attested recently, next attestation is possible at {next_at}")]
+    AttestationTooFrequent { next_at: Timestamp },
+
+    #[error("exactly one coin has to be sent to pay for Axelar gas")]
+    InvalidGasPayment {},
+
+    #[error("exchange rate must be greater than zero")]
+    ZeroExchangeRate {},
+
+    #[error("exchange rate at block {block_number} is not newer than the current one at block {last_block_number}")]
+    NonMonotonicExchangeRate {
+        block_number: u64,
+        last_block_number: u64,
+    },
+
+    #[error("exchange rate at timestamp {block_timestamp} is older than {max_age} seconds")]
+    StaleExchangeRate { block_timestamp: u64, max_age: u64 },
+
+    #[error("exchange rate at timestamp {block_timestamp} is ahead of current time {now} by more than {max_skew} seconds")]
+    FutureExchangeRate {
+        block_timestamp: u64,
+        now: u64,
+        max_skew: u64,
+    },
+
+    #[error("exchange rate has not been received yet")]
+    ExchangeRateNotSet {},
+
+    #[error("interchain query {query_id} is not registered by this contract")]
+    UnknownInterchainQuery { query_id: u64 },
+
+    #[error("reply {id} carries no data")]
+    EmptyReplyData { id: u64 },
+
+    #[error("invalid ibc-hooks origin: {reason}")]
+    InvalidIbcHooksOrigin { reason: String },
+
+    #[error("sender {actual} is not the ibc-hooks intermediate sender {expected} of given origin")]
+    IbcHooksSenderMismatch { expected: String, actual: String },
+
+    #[error("original sender {original_sender} is not a Neutron address, receiver must be set explicitly")]
+    IbcHooksReceiverRequired { original_sender: String },
+
+    #[error("{change} has to be queued while timelock delay is set")]
+    TimelockActive { change: String },
+
+    #[error("queued change {id} does not exist")]
+    QueuedChangeNotFound { id: u64 },
+
+    #[error("queued change {id} can not be executed before {eta}")]
+    QueuedChangeNotReady { id: u64, eta: Timestamp },
+
+    #[error("withdrawal {id} does not exist")]
+    WithdrawalNotFound { id: u64 },
+
+    #[error("withdrawal {id} can not be claimed before {release_at}")]
+    WithdrawalNotReady { id: u64, release_at: Timestamp },
+
+    #[error("queued withdrawals have to be claimed, approved or cancelled before migration")]
+    WithdrawalsQueued {},
+
+    #[error("transfers to EVM chains have to be acknowledged before migration")]
+    TransfersInFlight {},
+
+    #[error("contract holds no surplus of {denom}")]
+    NoSurplus { denom: String },
+
+    #[error("mint batch must have at least one output")]
+    EmptyMintBatch {},
+
+    #[error("mint batch has {size} outputs, at most {max_size} are allowed")]
+    MintBatchTooLarge { size: usize, max_size: usize },
+
+    #[error("output to {receiver} of mint batch has zero amount")]
+    ZeroMintOutput { receiver: String },
+
+    #[error("outputs of mint batch add up to {total}, but {deposited} has been deposited")]
+    MintBatchAmountMismatch { total: Uint128, deposited: Uint128 },
+
+    #[error("invalid referral code {referral}: {reason}")]
+    InvalidReferral { referral: String, reason: String },
+
+    #[error("invalid memo: {reason}")]
+    InvalidMemo { reason: String },
+
+    #[error("owner must be set when migrating from a single asset config")]
+    MigrationOwnerRequired {},
+
+    #[error("owner can only be set when migrating from a single asset config")]
+    UnexpectedMigrationOwner {},
+
+    #[error(
+        "canonical denom {denom} of single asset config is not a tokenfactory denom of contract"
+    )]
+    ForeignLegacyDenom { denom: String },
+
     #[error("unknown reply id: {id}")]
     UnknownReplyId { id: u64 },
 }
@@ -1,75 +1,2554 @@
-use crate::{state::CONFIG, ContractError, ContractResult};
-use cosmwasm_std::{attr, coin, BankMsg, Coin, CosmosMsg, DepsMut, Env, MessageInfo, Response};
-use neutron_sdk::bindings::{msg::NeutronMsg, query::NeutronQuery};
+use crate::{
+    contract::{BURN_TO_EVM_REPLY_ID, CREATE_DENOM_REPLY_ID, REGISTER_ESCROW_QUERY_REPLY_ID},
+    gmp::{
+        abi_encode_uint256s, eth_personal_message_hash, recover_evm_address, transfer_to_axelar,
+        transfer_to_evm, validate_evm_address, GmpFee, GmpMessage, GMP_TYPE_GENERAL_MESSAGE,
+        GMP_TYPE_TOKEN_TRANSFER,
+    },
+    ibc_hooks::{verify_ibc_hooks_origin, IbcHooksOrigin},
+    msg::{
+        validate_bridged_denom, validate_canonical_subdenom, validate_memo, validate_referral,
+        BurnIntent, BurnIntentSignDoc, IDEMPOTENCY_KEY_TTL, MAX_EXCHANGE_RATE_AGE,
+        MAX_EXCHANGE_RATE_CLOCK_SKEW, MAX_IDEMPOTENCY_KEY_LENGTH, MAX_MINT_BATCH_SIZE,
+    },
+    state::{
+        AdminAction, Asset, AttestationConfig, Bridge, BridgeStatus, Config, ConfigChange,
+        EscrowQuery, EvmTransfer, ExchangeRate, ExchangeRateSource, GmpConfig, IntentAccount,
+        LegacyConfig, OutboundTransfer, PendingClaim, QueuedChange, ReferralStats, Role,
+        RoutingPolicy, Withdrawal, WithdrawalQueueConfig, ADMIN_LOG, ADMIN_LOG_LENGTH, ASSETS,
+        ATTESTATION_CONFIG, BRIDGES, CANONICAL_DENOMS, CLAIM_NONCES, CONFIG, ESCROW_BALANCES,
+        ESCROW_QUERIES, ESCROW_QUERY_IDS, EXCHANGE_RATE, EXCHANGE_RATE_SOURCE, EXPECTED_BALANCES,
+        GMP_CONFIG, IDEMPOTENCY_KEYS, IDEMPOTENCY_KEY_EXPIRATIONS, INTENT_ACCOUNTS,
+        INTENT_DEPOSITS, LAST_ATTESTATION, NEXT_QUEUED_CHANGE_ID, NEXT_WITHDRAWAL_ID,
+        OUTBOUND_TRANSFERS, PAUSED, PENDING_ASSET, PENDING_CLAIMS, PENDING_ESCROW_QUERY,
+        PENDING_TRANSFER, QUEUED_CHANGES, QUEUED_PAYOUTS, RECEIVER_WITHDRAWALS, REFERRAL_STATS,
+        ROLES, SUCCESSOR, TIMELOCK_DELAY, WITHDRAWALS, WITHDRAWAL_QUEUES,
+    },
+    ContractError, ContractResult,
+};
+use cosmwasm_schema::serde::{de::DeserializeOwned, Serialize};
+use cosmwasm_std::{
+    attr, coin, to_json_string, to_json_vec, Addr, BankMsg, Binary, Coin, CosmosMsg, Decimal256,
+    Deps, DepsMut, Env, Event, MessageInfo, Order, Response, StdResult, Storage, SubMsg, Timestamp,
+    Uint128,
+};
+use cw_storage_plus::{Bound, Map};
+use neutron_sdk::{
+    bindings::{msg::NeutronMsg, query::NeutronQuery},
+    interchain_queries::v045::new_register_balance_query_msg,
+};
+use sha2::{Digest, Sha256};
 
+/// Native Neutron denom which pays for what the contract does on behalf of the sender: IBC fees
+/// of [`crate::msg::ExecuteMsg::BurnToEvm`], tokenfactory denom creation of
+/// [`crate::msg::ExecuteMsg::RegisterAsset`], interchain query deposit of
+/// [`crate::msg::ExecuteMsg::RegisterEscrowQuery`] and Axelar gas of
+/// [`crate::msg::ExecuteMsg::AttestSupply`]. Messages which spend nothing on behalf of
+/// the sender reject it.
+pub const FEE_DENOM: &str = "untrn";
+
+/// How many expired idempotency keys are pruned by every mint
+const IDEMPOTENCY_KEYS_PRUNE_LIMIT: usize = 10;
+
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn execute_mint(
     deps: DepsMut<NeutronQuery>,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     receiver: Option<String>,
+    idempotency_key: Option<String>,
+    source_chain: Option<String>,
+    source_address: Option<String>,
+    evm_sender: Option<String>,
+    ibc_hooks_origin: Option<IbcHooksOrigin>,
+    referral: Option<String>,
+    memo: Option<String>,
 ) -> ContractResult<Response<NeutronMsg>> {
-    let config = CONFIG.load(deps.storage)?;
+    ensure_not_paused(deps.as_ref())?;
+    ensure_not_migrated(deps.as_ref())?;
+    validate_attribution(referral.as_deref(), memo.as_deref())?;
     let sender = info.sender.to_string();
-    let receiver = receiver.map_or(Ok(info.sender), |addr| deps.api.addr_validate(&addr))?;
+    if let Some(origin) = &ibc_hooks_origin {
+        verify_ibc_hooks_origin(&info.sender, origin)?;
+    }
+
+    // messages coming from Axelar GMP carry the EVM account they originate from
+    let from_gmp = GMP_CONFIG
+        .may_load(deps.storage)?
+        .is_some_and(|gmp_config| gmp_config.sender == info.sender);
+    let gmp_origin = match (source_chain, source_address) {
+        (Some(source_chain), Some(source_address)) if from_gmp => {
+            Some((source_chain, source_address, evm_sender))
+        }
+        _ => None,
+    };
+
+    // funds coming over GMP can not be bounced back to sender, hence they are kept
+    // in contract if receiver is invalid
+    let receiver = match (receiver, &ibc_hooks_origin) {
+        // intermediate sender of ibc-hooks is not controlled by anyone
+        (None, Some(origin)) => {
+            Ok(deps
+                .api
+                .addr_validate(&origin.original_sender)
+                .map_err(|_| ContractError::IbcHooksReceiverRequired {
+                    original_sender: origin.original_sender.clone(),
+                })?)
+        }
+        (None, None) => Ok(info.sender.clone()),
+        (Some(addr), _) => match deps.api.addr_validate(&addr) {
+            Ok(addr) => Ok(addr),
+            Err(_) if gmp_origin.is_some() => Err(addr),
+            Err(err) => return Err(err.into()),
+        },
+    };
 
-    let bridged_funds =
-        find_denom(&info.funds, &config.bridged_denom)?.ok_or(ContractError::NothingToMint {})?;
+    let funds = parse_funds(deps.storage, &info.funds, BRIDGES, None)?;
+    let (bridged_funds, bridge) = funds.asset.ok_or(ContractError::NothingToMint {})?;
+    if bridge.status != BridgeStatus::Active {
+        return Err(ContractError::MintNotAllowed {
+            denom: bridged_funds.denom.clone(),
+            status: bridge.status,
+        });
+    }
+    ensure_within_bounds(&bridge, &bridged_funds.denom, bridged_funds.amount)?;
+    let asset = ASSETS.load(deps.storage, &bridge.canonical_subdenom)?;
+
+    prune_idempotency_keys(deps.storage, env.block.time, IDEMPOTENCY_KEYS_PRUNE_LIMIT)?;
+    if let Some(key) = &idempotency_key {
+        process_idempotency_key(deps.storage, env.block.time, &info.sender, key)?;
+    }
 
-    let mint_msg: CosmosMsg<NeutronMsg> =
-        NeutronMsg::submit_mint_tokens(config.canonical_denom, bridged_funds.amount, &receiver)
+    let mut response = match (receiver, gmp_origin) {
+        (Ok(receiver), _) => {
+            let mint_msg: CosmosMsg<NeutronMsg> = NeutronMsg::submit_mint_tokens(
+                &asset.canonical_denom,
+                bridged_funds.amount,
+                &receiver,
+            )
+            .into();
+            Response::new().add_message(mint_msg).add_attributes([
+                attr("action", "mint"),
+                attr("amount", bridged_funds.amount),
+                attr("sender", sender),
+                attr("receiver", receiver),
+            ])
+        }
+        (Err(invalid_receiver), Some((source_chain, source_address, evm_sender))) => {
+            let source_address = validate_evm_address(&source_address)?;
+            // when GMP call is made by a helper contract, funds belong to the account which
+            // called the helper rather than to the helper itself
+            let evm_address = match evm_sender {
+                Some(evm_sender) => validate_evm_address(&evm_sender)?,
+                None => source_address.clone(),
+            };
+            PENDING_CLAIMS.update(
+                deps.storage,
+                (&evm_address, &bridged_funds.denom),
+                |claim| -> ContractResult<_> {
+                    let mut claim = claim.unwrap_or(PendingClaim {
+                        source_chain: source_chain.clone(),
+                        canonical_denom: asset.canonical_denom.clone(),
+                        amount: Uint128::zero(),
+                    });
+                    claim.amount = claim.amount.checked_add(bridged_funds.amount)?;
+                    Ok(claim)
+                },
+            )?;
+            increase_expected_balance(deps.storage, &asset.canonical_denom, bridged_funds.amount)?;
+            let mint_msg: CosmosMsg<NeutronMsg> = NeutronMsg::submit_mint_tokens(
+                &asset.canonical_denom,
+                bridged_funds.amount,
+                &env.contract.address,
+            )
             .into();
+            Response::new().add_message(mint_msg).add_attributes([
+                attr("action", "escrow_mint"),
+                attr("amount", bridged_funds.amount),
+                attr("sender", sender),
+                attr("invalid_receiver", invalid_receiver),
+                attr("source_chain", source_chain),
+                attr("source_address", source_address),
+                attr("evm_address", evm_address),
+            ])
+        }
+        (Err(_), None) => unreachable!("invalid receiver is only tolerated for GMP messages"),
+    };
+    increase_expected_balance(deps.storage, &bridged_funds.denom, bridged_funds.amount)?;
+    if let Some(key) = idempotency_key {
+        response = response.add_attribute("idempotency_key", key);
+    }
+    if let Some(origin) = ibc_hooks_origin {
+        response = response.add_attributes([
+            attr("original_sender", origin.original_sender),
+            attr("ibc_channel", origin.channel),
+        ]);
+    }
+    attribute(
+        deps.storage,
+        response,
+        referral,
+        memo,
+        &asset.canonical_denom,
+        |stats| {
+            stats.minted = stats.minted.checked_add(bridged_funds.amount)?;
+            stats.mints += 1;
+            Ok(())
+        },
+    )
+}
 
-    Ok(Response::new().add_message(mint_msg).add_attributes([
-        attr("action", "mint"),
+pub(crate) fn execute_mint_batch(
+    deps: DepsMut<NeutronQuery>,
+    _env: Env,
+    info: MessageInfo,
+    outputs: Vec<(String, Uint128)>,
+) -> ContractResult<Response<NeutronMsg>> {
+    ensure_not_paused(deps.as_ref())?;
+    ensure_not_migrated(deps.as_ref())?;
+    if outputs.is_empty() {
+        return Err(ContractError::EmptyMintBatch {});
+    }
+    if outputs.len() > MAX_MINT_BATCH_SIZE {
+        return Err(ContractError::MintBatchTooLarge {
+            size: outputs.len(),
+            max_size: MAX_MINT_BATCH_SIZE,
+        });
+    }
+
+    let funds = parse_funds(deps.storage, &info.funds, BRIDGES, None)?;
+    let (bridged_funds, bridge) = funds.asset.ok_or(ContractError::NothingToMint {})?;
+    if bridge.status != BridgeStatus::Active {
+        return Err(ContractError::MintNotAllowed {
+            denom: bridged_funds.denom.clone(),
+            status: bridge.status,
+        });
+    }
+    ensure_within_bounds(&bridge, &bridged_funds.denom, bridged_funds.amount)?;
+    let asset = ASSETS.load(deps.storage, &bridge.canonical_subdenom)?;
+
+    let mut total = Uint128::zero();
+    let mut response = Response::new();
+    for (receiver, amount) in &outputs {
+        let receiver = deps.api.addr_validate(receiver)?;
+        if amount.is_zero() {
+            return Err(ContractError::ZeroMintOutput {
+                receiver: receiver.to_string(),
+            });
+        }
+        total = total.checked_add(*amount)?;
+        let mint_msg: CosmosMsg<NeutronMsg> =
+            NeutronMsg::submit_mint_tokens(&asset.canonical_denom, *amount, &receiver).into();
+        response = response.add_message(mint_msg).add_event(
+            Event::new("mint_output")
+                .add_attributes([attr("receiver", receiver), attr("amount", *amount)]),
+        );
+    }
+    if total != bridged_funds.amount {
+        return Err(ContractError::MintBatchAmountMismatch {
+            total,
+            deposited: bridged_funds.amount,
+        });
+    }
+    increase_expected_balance(deps.storage, &bridged_funds.denom, bridged_funds.amount)?;
+
+    Ok(response.add_attributes([
+        attr("action", "mint_batch"),
         attr("amount", bridged_funds.amount),
-        attr("sender", sender),
-        attr("receiver", receiver),
+        attr("sender", info.sender),
+        attr("outputs", outputs.len().to_string()),
     ]))
 }
 
 pub(crate) fn execute_burn(
     deps: DepsMut<NeutronQuery>,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     receiver: Option<String>,
+    bridged_denom: Option<String>,
+    referral: Option<String>,
+    memo: Option<String>,
 ) -> ContractResult<Response<NeutronMsg>> {
-    let config = CONFIG.load(deps.storage)?;
-    let sender = info.sender.to_string();
+    ensure_not_paused(deps.as_ref())?;
+    ensure_not_migrated(deps.as_ref())?;
+    validate_attribution(referral.as_deref(), memo.as_deref())?;
+    let sender = info.sender.clone();
     let receiver = receiver.map_or(Ok(info.sender), |addr| deps.api.addr_validate(&addr))?;
 
-    let amount_to_burn = find_denom(&info.funds, &config.canonical_denom)?
-        .ok_or(ContractError::NothingToBurn {})?
-        .amount;
+    let funds = parse_funds(deps.storage, &info.funds, CANONICAL_DENOMS, None)?;
+    let (canonical_funds, subdenom) = funds.asset.ok_or(ContractError::NothingToBurn {})?;
+    let amount_to_burn = canonical_funds.amount;
+    let asset = ASSETS.load(deps.storage, &subdenom)?;
+    let payout = route_burn(
+        deps.as_ref(),
+        &env,
+        &subdenom,
+        &asset,
+        amount_to_burn,
+        bridged_denom,
+    )?;
+
+    let burn_msg: CosmosMsg<NeutronMsg> =
+        NeutronMsg::submit_burn_tokens(&asset.canonical_denom, amount_to_burn).into();
+    let response = Response::new().add_message(burn_msg).add_attributes([
+        attr("action", "burn"),
+        attr("amount", amount_to_burn),
+        attr("sender", &sender),
+        attr("receiver", &receiver),
+    ]);
+    let response = attribute(
+        deps.storage,
+        response,
+        referral,
+        memo,
+        &asset.canonical_denom,
+        |stats| {
+            stats.burned = stats.burned.checked_add(amount_to_burn)?;
+            stats.burns += 1;
+            Ok(())
+        },
+    )?;
+
+    let Some(release_at) = withdrawal_release_at(deps.storage, &env, &subdenom, amount_to_burn)?
+    else {
+        decrease_expected_balances(deps.storage, &payout)?;
+        return Ok(response.add_message(BankMsg::Send {
+            to_address: receiver.to_string(),
+            amount: payout,
+        }));
+    };
+    let id = queue_withdrawal(
+        deps.storage,
+        &Withdrawal {
+            sender,
+            receiver,
+            canonical_denom: asset.canonical_denom,
+            amount: amount_to_burn,
+            payout,
+            release_at,
+            evm_transfer: None,
+        },
+    )?;
+
+    Ok(response.add_attributes([
+        attr("withdrawal_id", id.to_string()),
+        attr("release_at", release_at.to_string()),
+    ]))
+}
+
+pub(crate) fn execute_burn_to_evm(
+    deps: DepsMut<NeutronQuery>,
+    env: Env,
+    info: MessageInfo,
+    evm_receiver: String,
+    destination_chain: String,
+    gas_fee: Uint128,
+) -> ContractResult<Response<NeutronMsg>> {
+    ensure_not_paused(deps.as_ref())?;
+    ensure_not_migrated(deps.as_ref())?;
+    let gmp_config = GMP_CONFIG
+        .may_load(deps.storage)?
+        .ok_or(ContractError::GmpNotConfigured {})?;
+    let evm_receiver = validate_evm_address(&evm_receiver)?;
+
+    let funds = parse_funds(deps.storage, &info.funds, CANONICAL_DENOMS, Some(FEE_DENOM))?;
+    let (canonical_funds, subdenom) = funds.asset.ok_or(ContractError::NothingToBurn {})?;
+    let amount_to_burn = canonical_funds.amount;
+    if gas_fee >= amount_to_burn {
+        return Err(ContractError::GasFeeExceedsAmount {
+            fee: gas_fee,
+            amount: amount_to_burn,
+        });
+    }
+    let asset = ASSETS.load(deps.storage, &subdenom)?;
+    // only funds which came over Axelar can be routed back by it
+    let bridged_denom = asset
+        .bridged_denoms
+        .iter()
+        .find(|bridged_denom| gmp_config.bridged_denoms.contains(bridged_denom))
+        .ok_or_else(|| ContractError::NoGmpBridgedDenom {
+            subdenom: subdenom.clone(),
+        })?;
+    let [payout] = route_burn(
+        deps.as_ref(),
+        &env,
+        &subdenom,
+        &asset,
+        amount_to_burn,
+        Some(bridged_denom.clone()),
+    )?
+    .try_into()
+    .expect("burn routed to a preferred bridged denom is paid out in it alone");
 
     let burn_msg: CosmosMsg<NeutronMsg> =
-        NeutronMsg::submit_burn_tokens(config.canonical_denom, amount_to_burn).into();
+        NeutronMsg::submit_burn_tokens(&asset.canonical_denom, amount_to_burn).into();
+    let mut response = Response::new().add_message(burn_msg).add_attributes([
+        attr("action", "burn_to_evm"),
+        attr("amount", amount_to_burn),
+        attr("bridged_denom", &payout.denom),
+        attr("sender", &info.sender),
+        attr("destination_chain", &destination_chain),
+        attr("evm_receiver", &evm_receiver),
+    ]);
+    if let Some(fee) = funds.fee {
+        response = response.add_attribute("fee", fee.to_string());
+    }
+    let evm_transfer = EvmTransfer {
+        destination_chain,
+        evm_receiver,
+        gas_fee,
+    };
+
+    let Some(release_at) = withdrawal_release_at(deps.storage, &env, &subdenom, amount_to_burn)?
+    else {
+        decrease_expected_balance(deps.storage, &payout.denom, payout.amount)?;
+        let transfer_msg = send_to_evm(
+            deps,
+            &env,
+            &gmp_config,
+            &info.sender,
+            &asset.canonical_denom,
+            payout,
+            &evm_transfer,
+        )?;
+        return Ok(response.add_submessage(transfer_msg));
+    };
+    let id = queue_withdrawal(
+        deps.storage,
+        &Withdrawal {
+            sender: info.sender.clone(),
+            receiver: info.sender,
+            canonical_denom: asset.canonical_denom,
+            amount: amount_to_burn,
+            payout: vec![payout],
+            release_at,
+            evm_transfer: Some(evm_transfer),
+        },
+    )?;
+
+    Ok(response.add_attributes([
+        attr("withdrawal_id", id.to_string()),
+        attr("release_at", release_at.to_string()),
+    ]))
+}
+
+pub(crate) fn execute_deposit_for_intents(
+    deps: DepsMut<NeutronQuery>,
+    _env: Env,
+    info: MessageInfo,
+    pubkey: Binary,
+) -> ContractResult<Response<NeutronMsg>> {
+    ensure_not_migrated(deps.as_ref())?;
+    // both compressed and uncompressed secp256k1 public keys are accepted by `secp256k1_verify`
+    if pubkey.len() != 33 && pubkey.len() != 65 {
+        return Err(ContractError::InvalidPublicKey {});
+    }
+
+    let (canonical_funds, _subdenom) =
+        parse_funds(deps.storage, &info.funds, CANONICAL_DENOMS, None)?
+            .asset
+            .ok_or(ContractError::NothingToDeposit {})?;
+
+    let next_nonce = INTENT_ACCOUNTS
+        .may_load(deps.storage, &info.sender)?
+        .map_or(0, |account| account.next_nonce);
+    INTENT_ACCOUNTS.save(
+        deps.storage,
+        &info.sender,
+        &IntentAccount { pubkey, next_nonce },
+    )?;
+    INTENT_DEPOSITS.update(
+        deps.storage,
+        (&info.sender, &canonical_funds.denom),
+        |deposit| -> StdResult<_> {
+            Ok(deposit
+                .unwrap_or_default()
+                .checked_add(canonical_funds.amount)?)
+        },
+    )?;
+    increase_expected_balance(deps.storage, &canonical_funds.denom, canonical_funds.amount)?;
+
+    Ok(Response::new().add_attributes([
+        attr("action", "deposit_for_intents"),
+        attr("amount", canonical_funds.amount),
+        attr("denom", &canonical_funds.denom),
+        attr("owner", info.sender),
+    ]))
+}
+
+pub(crate) fn execute_withdraw_intent_deposit(
+    deps: DepsMut<NeutronQuery>,
+    _env: Env,
+    info: MessageInfo,
+    denom: String,
+    amount: Uint128,
+) -> ContractResult<Response<NeutronMsg>> {
+    nonpayable(&info, None)?;
+    take_intent_deposit(deps.storage, &info.sender, &denom, amount)?;
+    decrease_expected_balance(deps.storage, &denom, amount)?;
+
+    let send_msg = BankMsg::Send {
+        to_address: info.sender.to_string(),
+        amount: vec![coin(amount.u128(), &denom)],
+    };
+
+    Ok(Response::new().add_message(send_msg).add_attributes([
+        attr("action", "withdraw_intent_deposit"),
+        attr("amount", amount),
+        attr("denom", denom),
+        attr("owner", info.sender),
+    ]))
+}
+
+pub(crate) fn execute_burn_intent(
+    deps: DepsMut<NeutronQuery>,
+    env: Env,
+    info: MessageInfo,
+    intent: BurnIntent,
+    signature: Binary,
+    relayer_fee: Uint128,
+) -> ContractResult<Response<NeutronMsg>> {
+    nonpayable(&info, None)?;
+    ensure_not_paused(deps.as_ref())?;
+    ensure_not_migrated(deps.as_ref())?;
+    let owner = deps.api.addr_validate(&intent.owner)?;
+    let receiver = deps.api.addr_validate(&intent.receiver)?;
+
+    if env.block.time > intent.expires_at {
+        return Err(ContractError::IntentExpired {
+            expires_at: intent.expires_at,
+        });
+    }
+    if relayer_fee > intent.max_relayer_fee {
+        return Err(ContractError::RelayerFeeTooHigh {
+            fee: relayer_fee,
+            max: intent.max_relayer_fee,
+        });
+    }
+    if relayer_fee >= intent.amount {
+        return Err(ContractError::RelayerFeeExceedsAmount {
+            fee: relayer_fee,
+            amount: intent.amount,
+        });
+    }
+
+    let mut account = INTENT_ACCOUNTS
+        .may_load(deps.storage, &owner)?
+        .ok_or_else(|| ContractError::IntentAccountNotFound {
+            address: owner.to_string(),
+        })?;
+    if intent.nonce != account.next_nonce {
+        return Err(ContractError::InvalidNonce {
+            expected: account.next_nonce,
+            actual: intent.nonce,
+        });
+    }
+    let digest = burn_intent_digest(&env, &intent)?;
+    if !deps
+        .api
+        .secp256k1_verify(&digest, &signature, &account.pubkey)?
+    {
+        return Err(ContractError::InvalidSignature {});
+    }
+    account.next_nonce += 1;
+    INTENT_ACCOUNTS.save(deps.storage, &owner, &account)?;
+
+    take_intent_deposit(deps.storage, &owner, &intent.canonical_denom, intent.amount)?;
+    let subdenom = CANONICAL_DENOMS.load(deps.storage, &intent.canonical_denom)?;
+    let asset = ASSETS.load(deps.storage, &subdenom)?;
+    let payout = route_burn(deps.as_ref(), &env, &subdenom, &asset, intent.amount, None)?;
+    decrease_expected_balance(deps.storage, &intent.canonical_denom, intent.amount)?;
+    let (receiver_payout, relayer_payout) = split_payout(payout, relayer_fee);
+    decrease_expected_balances(deps.storage, &relayer_payout)?;
+
+    let burn_msg: CosmosMsg<NeutronMsg> =
+        NeutronMsg::submit_burn_tokens(&asset.canonical_denom, intent.amount).into();
+    let mut response = Response::new().add_message(burn_msg).add_attributes([
+        attr("action", "burn_intent"),
+        attr("amount", intent.amount),
+        attr("owner", &owner),
+        attr("receiver", &receiver),
+        attr("nonce", intent.nonce.to_string()),
+        attr("relayer", &info.sender),
+        attr("relayer_fee", relayer_fee),
+    ]);
+    // the relayer is paid right away, only the receiver waits for a queued withdrawal
+    match withdrawal_release_at(deps.storage, &env, &subdenom, intent.amount)? {
+        Some(release_at) => {
+            let id = queue_withdrawal(
+                deps.storage,
+                &Withdrawal {
+                    sender: owner,
+                    receiver,
+                    canonical_denom: asset.canonical_denom,
+                    amount: intent.amount - relayer_fee,
+                    payout: receiver_payout,
+                    release_at,
+                    evm_transfer: None,
+                },
+            )?;
+            response = response.add_attributes([
+                attr("withdrawal_id", id.to_string()),
+                attr("release_at", release_at.to_string()),
+            ]);
+        }
+        None => {
+            decrease_expected_balances(deps.storage, &receiver_payout)?;
+            response = response.add_message(BankMsg::Send {
+                to_address: receiver.to_string(),
+                amount: receiver_payout,
+            });
+        }
+    }
+    if !relayer_payout.is_empty() {
+        response = response.add_message(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: relayer_payout,
+        });
+    }
+
+    Ok(response)
+}
+
+pub(crate) fn execute_claim_pending(
+    deps: DepsMut<NeutronQuery>,
+    env: Env,
+    info: MessageInfo,
+    evm_address: String,
+    bridged_denom: String,
+    receiver: String,
+    signature: Binary,
+) -> ContractResult<Response<NeutronMsg>> {
+    nonpayable(&info, None)?;
+    ensure_not_migrated(deps.as_ref())?;
+    let evm_address = validate_evm_address(&evm_address)?;
+    let receiver = deps.api.addr_validate(&receiver)?;
+    let (claim, nonce) = take_pending_claim(deps.storage, &evm_address, &bridged_denom)?;
+
+    let message = claim_message(&env, &bridged_denom, &claim, receiver.as_str(), nonce);
+    let signer = recover_evm_address(deps.api, &eth_personal_message_hash(&message), &signature)?;
+    if signer != evm_address {
+        return Err(ContractError::InvalidSignature {});
+    }
+
+    decrease_expected_balance(deps.storage, &claim.canonical_denom, claim.amount)?;
     let send_msg = BankMsg::Send {
         to_address: receiver.to_string(),
-        amount: vec![coin(amount_to_burn.u128(), config.bridged_denom)],
+        amount: vec![coin(claim.amount.u128(), claim.canonical_denom)],
+    };
+
+    Ok(Response::new().add_message(send_msg).add_attributes([
+        attr("action", "claim_pending"),
+        attr("amount", claim.amount),
+        attr("evm_address", evm_address),
+        attr("receiver", receiver),
+    ]))
+}
+
+pub(crate) fn execute_return_pending(
+    deps: DepsMut<NeutronQuery>,
+    env: Env,
+    info: MessageInfo,
+    evm_address: String,
+    bridged_denom: String,
+) -> ContractResult<Response<NeutronMsg>> {
+    nonpayable(&info, None)?;
+    ensure_not_migrated(deps.as_ref())?;
+    ensure_owner(deps.as_ref(), &info.sender)?;
+    let gmp_config = GMP_CONFIG
+        .may_load(deps.storage)?
+        .ok_or(ContractError::GmpNotConfigured {})?;
+    let evm_address = validate_evm_address(&evm_address)?;
+    let (claim, _nonce) = take_pending_claim(deps.storage, &evm_address, &bridged_denom)?;
+    log_admin_action(
+        deps.storage,
+        &env,
+        &info.sender,
+        "return_pending",
+        Some(to_json_string(&claim)?),
+        None,
+    )?;
+
+    decrease_expected_balance(deps.storage, &claim.canonical_denom, claim.amount)?;
+    decrease_expected_balance(deps.storage, &bridged_denom, claim.amount)?;
+    let burn_msg: CosmosMsg<NeutronMsg> =
+        NeutronMsg::submit_burn_tokens(&claim.canonical_denom, claim.amount).into();
+    let transfer_msg: CosmosMsg<NeutronMsg> = transfer_to_evm(
+        deps.as_ref(),
+        &env,
+        &gmp_config,
+        coin(claim.amount.u128(), &bridged_denom),
+        &claim.source_chain,
+        &evm_address,
+    )?
+    .into();
+
+    Ok(Response::new()
+        .add_messages([burn_msg, transfer_msg])
+        .add_attributes([
+            attr("action", "return_pending"),
+            attr("amount", claim.amount),
+            attr("bridged_denom", bridged_denom),
+            attr("destination_chain", claim.source_chain),
+            attr("evm_address", evm_address),
+        ]))
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn execute_set_gmp_config(
+    deps: DepsMut<NeutronQuery>,
+    env: Env,
+    info: MessageInfo,
+    sender: String,
+    channel: String,
+    gateway: String,
+    gas_service: String,
+    bridged_denoms: Vec<String>,
+) -> ContractResult<Response<NeutronMsg>> {
+    nonpayable(&info, None)?;
+    execute_change_directly(
+        deps,
+        &env,
+        &info.sender,
+        ConfigChange::SetGmpConfig {
+            sender,
+            channel,
+            gateway,
+            gas_service,
+            bridged_denoms,
+        },
+    )
+}
+
+pub(crate) fn execute_attest_supply(
+    deps: DepsMut<NeutronQuery>,
+    env: Env,
+    info: MessageInfo,
+) -> ContractResult<Response<NeutronMsg>> {
+    ensure_not_migrated(deps.as_ref())?;
+    let gmp_config = GMP_CONFIG
+        .may_load(deps.storage)?
+        .ok_or(ContractError::GmpNotConfigured {})?;
+    let attestation_config = ATTESTATION_CONFIG
+        .may_load(deps.storage)?
+        .ok_or(ContractError::AttestationNotConfigured {})?;
+    if let Some(last_attestation) = LAST_ATTESTATION.may_load(deps.storage)? {
+        let next_at = last_attestation.plus_seconds(attestation_config.min_interval);
+        if env.block.time < next_at {
+            return Err(ContractError::AttestationTooFrequent { next_at });
+        }
+    }
+    nonpayable(&info, Some(FEE_DENOM))?;
+    let [gas_payment] = info.funds.as_slice() else {
+        return Err(ContractError::InvalidGasPayment {});
+    };
+    if gas_payment.amount.is_zero() {
+        return Err(ContractError::InvalidGasPayment {});
     }
+
+    let asset = ASSETS.load(deps.storage, &attestation_config.canonical_subdenom)?;
+    let supply = deps.querier.query_supply(&asset.canonical_denom)?.amount;
+    let mut reserves = Uint128::zero();
+    for bridged_denom in &asset.bridged_denoms {
+        let balance = deps
+            .querier
+            .query_balance(&env.contract.address, bridged_denom)?;
+        reserves = reserves.checked_add(balance.amount)?;
+    }
+    let payload = abi_encode_uint256s(&[
+        supply.into(),
+        reserves.into(),
+        env.block.height.into(),
+        env.block.time.seconds().into(),
+    ]);
+    LAST_ATTESTATION.save(deps.storage, &env.block.time)?;
+
+    let memo = GmpMessage {
+        destination_chain: attestation_config.destination_chain.clone(),
+        destination_address: attestation_config.destination_address.clone(),
+        payload: Some(payload),
+        type_: GMP_TYPE_GENERAL_MESSAGE,
+        fee: Some(GmpFee {
+            amount: gas_payment.amount.to_string(),
+            recipient: gmp_config.gas_service.clone(),
+        }),
+    };
+    let transfer_msg =
+        transfer_to_axelar(deps.as_ref(), &env, &gmp_config, gas_payment.clone(), &memo)?;
+
+    Ok(Response::new().add_message(transfer_msg).add_attributes([
+        attr("action", "attest_supply"),
+        attr("canonical_denom", asset.canonical_denom),
+        attr("supply", supply),
+        attr("reserves", reserves),
+        attr("destination_chain", attestation_config.destination_chain),
+        attr(
+            "destination_address",
+            attestation_config.destination_address,
+        ),
+    ]))
+}
+
+pub(crate) fn execute_set_attestation_config(
+    deps: DepsMut<NeutronQuery>,
+    env: Env,
+    info: MessageInfo,
+    canonical_subdenom: String,
+    destination_chain: String,
+    destination_address: String,
+    min_interval: u64,
+) -> ContractResult<Response<NeutronMsg>> {
+    nonpayable(&info, None)?;
+    ensure_owner(deps.as_ref(), &info.sender)?;
+    if !ASSETS.has(deps.storage, &canonical_subdenom) {
+        return Err(ContractError::AssetNotRegistered {
+            subdenom: canonical_subdenom,
+        });
+    }
+    let attestation_config = AttestationConfig {
+        canonical_subdenom,
+        destination_chain,
+        destination_address: validate_evm_address(&destination_address)?,
+        min_interval,
+    };
+    let old_attestation_config = ATTESTATION_CONFIG.may_load(deps.storage)?;
+    ATTESTATION_CONFIG.save(deps.storage, &attestation_config)?;
+    log_admin_action(
+        deps.storage,
+        &env,
+        &info.sender,
+        "set_attestation_config",
+        old_attestation_config
+            .as_ref()
+            .map(to_json_string)
+            .transpose()?,
+        Some(to_json_string(&attestation_config)?),
+    )?;
+
+    Ok(Response::new().add_attributes([
+        attr("action", "set_attestation_config"),
+        attr("canonical_subdenom", attestation_config.canonical_subdenom),
+        attr("destination_chain", attestation_config.destination_chain),
+        attr(
+            "destination_address",
+            attestation_config.destination_address,
+        ),
+        attr("min_interval", min_interval.to_string()),
+    ]))
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn execute_update_exchange_rate(
+    deps: DepsMut<NeutronQuery>,
+    env: Env,
+    info: MessageInfo,
+    source_chain: String,
+    source_address: String,
+    rate: Decimal256,
+    block_number: u64,
+    block_timestamp: u64,
+) -> ContractResult<Response<NeutronMsg>> {
+    // both Axelar GMP itself and the EVM contract on the other side have to be trusted
+    let from_gmp = GMP_CONFIG
+        .may_load(deps.storage)?
+        .is_some_and(|gmp_config| gmp_config.sender == info.sender);
+    let from_source = EXCHANGE_RATE_SOURCE
+        .may_load(deps.storage)?
+        .is_some_and(|source| {
+            source.source_chain == source_chain
+                && validate_evm_address(&source_address)
+                    .is_ok_and(|address| address == source.source_address)
+        });
+    if !from_gmp || !from_source {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if rate.is_zero() {
+        return Err(ContractError::ZeroExchangeRate {});
+    }
+    if block_timestamp.saturating_add(MAX_EXCHANGE_RATE_AGE) < env.block.time.seconds() {
+        return Err(ContractError::StaleExchangeRate {
+            block_timestamp,
+            max_age: MAX_EXCHANGE_RATE_AGE,
+        });
+    }
+    if block_timestamp > env.block.time.seconds() + MAX_EXCHANGE_RATE_CLOCK_SKEW {
+        return Err(ContractError::FutureExchangeRate {
+            block_timestamp,
+            now: env.block.time.seconds(),
+            max_skew: MAX_EXCHANGE_RATE_CLOCK_SKEW,
+        });
+    }
+    if let Some(last) = EXCHANGE_RATE.may_load(deps.storage)? {
+        if block_number <= last.block_number {
+            return Err(ContractError::NonMonotonicExchangeRate {
+                block_number,
+                last_block_number: last.block_number,
+            });
+        }
+    }
+    EXCHANGE_RATE.save(
+        deps.storage,
+        &ExchangeRate {
+            rate,
+            block_number,
+            block_timestamp,
+            updated_at: env.block.time,
+        },
+    )?;
+
+    Ok(Response::new().add_attributes([
+        attr("action", "update_exchange_rate"),
+        attr("rate", rate.to_string()),
+        attr("block_number", block_number.to_string()),
+        attr("block_timestamp", block_timestamp.to_string()),
+    ]))
+}
+
+pub(crate) fn execute_set_exchange_rate_source(
+    deps: DepsMut<NeutronQuery>,
+    env: Env,
+    info: MessageInfo,
+    source_chain: String,
+    source_address: String,
+) -> ContractResult<Response<NeutronMsg>> {
+    nonpayable(&info, None)?;
+    execute_change_directly(
+        deps,
+        &env,
+        &info.sender,
+        ConfigChange::SetExchangeRateSource {
+            source_chain,
+            source_address,
+        },
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn execute_register_escrow_query(
+    deps: DepsMut<NeutronQuery>,
+    env: Env,
+    info: MessageInfo,
+    bridged_denom: String,
+    connection_id: String,
+    escrow_address: String,
+    remote_denom: String,
+    update_period: u64,
+) -> ContractResult<Response<NeutronMsg>> {
+    ensure_not_migrated(deps.as_ref())?;
+    ensure_role(deps.as_ref(), Role::BridgeManager, &info.sender)?;
+    // interchain query deposit is paid with fee funds
+    nonpayable(&info, Some(FEE_DENOM))?;
+    if !BRIDGES.has(deps.storage, &bridged_denom) {
+        return Err(ContractError::BridgedDenomNotRegistered {
+            denom: bridged_denom,
+        });
+    }
+
+    let mut response = Response::new();
+    let old_escrow_query = ESCROW_QUERIES.may_load(deps.storage, &bridged_denom)?;
+    if let Some(query_id) = old_escrow_query
+        .as_ref()
+        .and_then(|escrow_query| escrow_query.query_id)
+    {
+        ESCROW_QUERY_IDS.remove(deps.storage, query_id);
+        ESCROW_BALANCES.remove(deps.storage, &bridged_denom);
+        response = response.add_message(NeutronMsg::remove_interchain_query(query_id));
+    }
+
+    let register_msg = new_register_balance_query_msg(
+        connection_id.clone(),
+        escrow_address.clone(),
+        remote_denom.clone(),
+        update_period,
+    )?;
+    let escrow_query = EscrowQuery {
+        connection_id: connection_id.clone(),
+        escrow_address: escrow_address.clone(),
+        remote_denom: remote_denom.clone(),
+        update_period,
+        query_id: None,
+    };
+    ESCROW_QUERIES.save(deps.storage, &bridged_denom, &escrow_query)?;
+    PENDING_ESCROW_QUERY.save(deps.storage, &bridged_denom)?;
+    log_admin_action(
+        deps.storage,
+        &env,
+        &info.sender,
+        "register_escrow_query",
+        old_escrow_query.as_ref().map(to_json_string).transpose()?,
+        Some(to_json_string(&escrow_query)?),
+    )?;
+
+    Ok(response
+        .add_submessage(SubMsg::reply_on_success(
+            register_msg,
+            REGISTER_ESCROW_QUERY_REPLY_ID,
+        ))
+        .add_attributes([
+            attr("action", "register_escrow_query"),
+            attr("bridged_denom", bridged_denom),
+            attr("connection_id", connection_id),
+            attr("escrow_address", escrow_address),
+            attr("remote_denom", remote_denom),
+        ]))
+}
+
+pub(crate) fn execute_register_asset(
+    deps: DepsMut<NeutronQuery>,
+    env: Env,
+    info: MessageInfo,
+    canonical_subdenom: String,
+    bridged_denoms: Vec<String>,
+) -> ContractResult<Response<NeutronMsg>> {
+    // creation of tokenfactory denom is paid for with fee funds
+    nonpayable(&info, Some(FEE_DENOM))?;
+    execute_change_directly(
+        deps,
+        &env,
+        &info.sender,
+        ConfigChange::RegisterAsset {
+            canonical_subdenom,
+            bridged_denoms,
+        },
+    )
+}
+
+pub(crate) fn execute_add_bridged_denom(
+    deps: DepsMut<NeutronQuery>,
+    env: Env,
+    info: MessageInfo,
+    canonical_subdenom: String,
+    bridged_denom: String,
+) -> ContractResult<Response<NeutronMsg>> {
+    nonpayable(&info, None)?;
+    execute_change_directly(
+        deps,
+        &env,
+        &info.sender,
+        ConfigChange::AddBridgedDenom {
+            canonical_subdenom,
+            bridged_denom,
+        },
+    )
+}
+
+pub(crate) fn execute_set_bridge_status(
+    deps: DepsMut<NeutronQuery>,
+    env: Env,
+    info: MessageInfo,
+    bridged_denom: String,
+    status: BridgeStatus,
+) -> ContractResult<Response<NeutronMsg>> {
+    nonpayable(&info, None)?;
+    execute_change_directly(
+        deps,
+        &env,
+        &info.sender,
+        ConfigChange::SetBridgeStatus {
+            bridged_denom,
+            status,
+        },
+    )
+}
+
+pub(crate) fn execute_set_amount_bounds(
+    deps: DepsMut<NeutronQuery>,
+    env: Env,
+    info: MessageInfo,
+    bridged_denom: String,
+    min_amount: Option<Uint128>,
+    max_amount: Option<Uint128>,
+) -> ContractResult<Response<NeutronMsg>> {
+    nonpayable(&info, None)?;
+    execute_change_directly(
+        deps,
+        &env,
+        &info.sender,
+        ConfigChange::SetAmountBounds {
+            bridged_denom,
+            min_amount,
+            max_amount,
+        },
+    )
+}
+
+pub(crate) fn execute_set_withdrawal_queue(
+    deps: DepsMut<NeutronQuery>,
+    env: Env,
+    info: MessageInfo,
+    canonical_subdenom: String,
+    config: Option<WithdrawalQueueConfig>,
+) -> ContractResult<Response<NeutronMsg>> {
+    nonpayable(&info, None)?;
+    execute_change_directly(
+        deps,
+        &env,
+        &info.sender,
+        ConfigChange::SetWithdrawalQueue {
+            canonical_subdenom,
+            config,
+        },
+    )
+}
+
+pub(crate) fn execute_claim_withdrawal(
+    deps: DepsMut<NeutronQuery>,
+    env: Env,
+    info: MessageInfo,
+    id: u64,
+) -> ContractResult<Response<NeutronMsg>> {
+    nonpayable(&info, None)?;
+    ensure_not_paused(deps.as_ref())?;
+    ensure_not_migrated(deps.as_ref())?;
+    let release_at = WITHDRAWALS
+        .may_load(deps.storage, id)?
+        .ok_or(ContractError::WithdrawalNotFound { id })?
+        .release_at;
+    if env.block.time < release_at {
+        return Err(ContractError::WithdrawalNotReady { id, release_at });
+    }
+    let withdrawal = take_withdrawal(deps.storage, id)?;
+    release_withdrawal(deps, &env, id, withdrawal, "claim_withdrawal")
+}
+
+pub(crate) fn execute_approve_withdrawal(
+    deps: DepsMut<NeutronQuery>,
+    env: Env,
+    info: MessageInfo,
+    id: u64,
+) -> ContractResult<Response<NeutronMsg>> {
+    nonpayable(&info, None)?;
+    ensure_not_migrated(deps.as_ref())?;
+    ensure_role(deps.as_ref(), Role::Guardian, &info.sender)?;
+    let withdrawal = take_withdrawal(deps.storage, id)?;
+    log_admin_action(
+        deps.storage,
+        &env,
+        &info.sender,
+        "approve_withdrawal",
+        Some(to_json_string(&withdrawal)?),
+        None,
+    )?;
+    release_withdrawal(deps, &env, id, withdrawal, "approve_withdrawal")
+}
+
+pub(crate) fn execute_cancel_withdrawal(
+    deps: DepsMut<NeutronQuery>,
+    env: Env,
+    info: MessageInfo,
+    id: u64,
+) -> ContractResult<Response<NeutronMsg>> {
+    nonpayable(&info, None)?;
+    ensure_not_migrated(deps.as_ref())?;
+    ensure_role(deps.as_ref(), Role::Guardian, &info.sender)?;
+    let withdrawal = take_withdrawal(deps.storage, id)?;
+    log_admin_action(
+        deps.storage,
+        &env,
+        &info.sender,
+        "cancel_withdrawal",
+        Some(to_json_string(&withdrawal)?),
+        None,
+    )?;
+
+    // bridged funds have never left the contract, so canonical funds are backed again
+    let mint_msg: CosmosMsg<NeutronMsg> = NeutronMsg::submit_mint_tokens(
+        withdrawal.canonical_denom,
+        withdrawal.amount,
+        &withdrawal.sender,
+    )
     .into();
+    Ok(Response::new().add_message(mint_msg).add_attributes([
+        attr("action", "cancel_withdrawal"),
+        attr("id", id.to_string()),
+        attr("amount", withdrawal.amount),
+        attr("sender", withdrawal.sender),
+    ]))
+}
+
+pub(crate) fn execute_set_routing_policy(
+    deps: DepsMut<NeutronQuery>,
+    env: Env,
+    info: MessageInfo,
+    canonical_subdenom: String,
+    routing_policy: RoutingPolicy,
+) -> ContractResult<Response<NeutronMsg>> {
+    nonpayable(&info, None)?;
+    execute_change_directly(
+        deps,
+        &env,
+        &info.sender,
+        ConfigChange::SetRoutingPolicy {
+            canonical_subdenom,
+            routing_policy,
+        },
+    )
+}
+
+pub(crate) fn execute_migrate_to_successor(
+    deps: DepsMut<NeutronQuery>,
+    env: Env,
+    info: MessageInfo,
+    successor: String,
+) -> ContractResult<Response<NeutronMsg>> {
+    nonpayable(&info, None)?;
+    ensure_not_migrated(deps.as_ref())?;
+    ensure_owner(deps.as_ref(), &info.sender)?;
+    let successor = deps.api.addr_validate(&successor)?;
+    // payouts of queued withdrawals are part of reserves, which are handed over to the successor
+    if !WITHDRAWALS.is_empty(deps.storage) {
+        return Err(ContractError::WithdrawalsQueued {});
+    }
+    // failed transfers are refunded by minting canonical funds, which requires denom admin
+    if !OUTBOUND_TRANSFERS.is_empty(deps.storage) || PENDING_TRANSFER.exists(deps.storage) {
+        return Err(ContractError::TransfersInFlight {});
+    }
+
+    let assets = ASSETS
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, asset)| asset))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut response = Response::new();
+    for asset in assets {
+        let change_admin_msg: CosmosMsg<NeutronMsg> =
+            NeutronMsg::submit_change_admin(&asset.canonical_denom, &successor).into();
+        response = response.add_message(change_admin_msg).add_event(
+            Event::new("change_denom_admin").add_attributes([
+                attr("denom", &asset.canonical_denom),
+                attr("new_admin", &successor),
+            ]),
+        );
+
+        for bridged_denom in asset.bridged_denoms {
+            EXPECTED_BALANCES.remove(deps.storage, &bridged_denom);
+            // it is impossible to send zero coins, so we only transfer reserves if there are any
+            let reserves = deps
+                .querier
+                .query_balance(&env.contract.address, bridged_denom)?;
+            if !reserves.amount.is_zero() {
+                response = response
+                    .add_message(BankMsg::Send {
+                        to_address: successor.to_string(),
+                        amount: vec![reserves.clone()],
+                    })
+                    .add_event(Event::new("transfer_reserves").add_attributes([
+                        attr("amount", reserves.amount),
+                        attr("denom", reserves.denom),
+                        attr("receiver", &successor),
+                    ]));
+            }
+        }
+    }
+
+    SUCCESSOR.save(deps.storage, &successor)?;
+    log_admin_action(
+        deps.storage,
+        &env,
+        &info.sender,
+        "migrate_to_successor",
+        None,
+        Some(to_json_string(&successor)?),
+    )?;
+    Ok(response
+        .add_event(Event::new("disable_mint_and_burn"))
+        .add_attributes([
+            attr("action", "migrate_to_successor"),
+            attr("successor", successor),
+        ]))
+}
+
+pub(crate) fn execute_sweep_surplus(
+    deps: DepsMut<NeutronQuery>,
+    env: Env,
+    info: MessageInfo,
+    denom: String,
+    to: String,
+) -> ContractResult<Response<NeutronMsg>> {
+    nonpayable(&info, None)?;
+    // surplus of fee denom consists of leftover fees, which are managed by fee managers
+    if denom == FEE_DENOM {
+        ensure_role(deps.as_ref(), Role::FeeManager, &info.sender)?;
+    } else {
+        ensure_owner(deps.as_ref(), &info.sender)?;
+    }
+    let to = deps.api.addr_validate(&to)?;
+
+    let balance = deps.querier.query_balance(&env.contract.address, &denom)?;
+    let (_expected, surplus) = surplus(deps.as_ref(), &env, &balance)?;
+    if surplus.is_zero() {
+        return Err(ContractError::NoSurplus { denom });
+    }
+    let swept = coin(surplus.u128(), &denom);
+    log_admin_action(
+        deps.storage,
+        &env,
+        &info.sender,
+        "sweep_surplus",
+        None,
+        Some(to_json_string(&swept)?),
+    )?;
 
     Ok(Response::new()
-        .add_messages([burn_msg, send_msg])
+        .add_message(BankMsg::Send {
+            to_address: to.to_string(),
+            amount: vec![swept],
+        })
         .add_attributes([
-            attr("action", "burn"),
-            attr("amount", amount_to_burn),
-            attr("sender", sender),
-            attr("receiver", receiver),
+            attr("action", "sweep_surplus"),
+            attr("denom", denom),
+            attr("amount", surplus),
+            attr("to", to),
         ]))
 }
 
-pub fn find_denom<'a>(funds: &'a [Coin], target_denom: &str) -> ContractResult<Option<&'a Coin>> {
-    match funds.len() {
-        0 => Ok(None),
-        1 => Ok({
-            let fund = &funds[0];
-            if fund.denom == target_denom {
-                Some(fund)
-            } else {
-                None
+pub(crate) fn execute_grant_role(
+    deps: DepsMut<NeutronQuery>,
+    env: Env,
+    info: MessageInfo,
+    role: Role,
+    address: String,
+) -> ContractResult<Response<NeutronMsg>> {
+    nonpayable(&info, None)?;
+    ensure_owner(deps.as_ref(), &info.sender)?;
+    let address = deps.api.addr_validate(&address)?;
+    ROLES.save(deps.storage, (&role.to_string(), &address), &())?;
+    log_admin_action(
+        deps.storage,
+        &env,
+        &info.sender,
+        "grant_role",
+        None,
+        Some(to_json_string(&(role, &address))?),
+    )?;
+    Ok(Response::new().add_attributes([
+        attr("action", "grant_role"),
+        attr("role", role.to_string()),
+        attr("address", address),
+    ]))
+}
+
+pub(crate) fn execute_revoke_role(
+    deps: DepsMut<NeutronQuery>,
+    env: Env,
+    info: MessageInfo,
+    role: Role,
+    address: String,
+) -> ContractResult<Response<NeutronMsg>> {
+    nonpayable(&info, None)?;
+    ensure_owner(deps.as_ref(), &info.sender)?;
+    let address = deps.api.addr_validate(&address)?;
+    ROLES.remove(deps.storage, (&role.to_string(), &address));
+    log_admin_action(
+        deps.storage,
+        &env,
+        &info.sender,
+        "revoke_role",
+        Some(to_json_string(&(role, &address))?),
+        None,
+    )?;
+    Ok(Response::new().add_attributes([
+        attr("action", "revoke_role"),
+        attr("role", role.to_string()),
+        attr("address", address),
+    ]))
+}
+
+pub(crate) fn execute_pause(
+    deps: DepsMut<NeutronQuery>,
+    env: Env,
+    info: MessageInfo,
+) -> ContractResult<Response<NeutronMsg>> {
+    nonpayable(&info, None)?;
+    ensure_role(deps.as_ref(), Role::Guardian, &info.sender)?;
+    set_paused(deps.storage, &env, &info.sender, "pause", true)?;
+    Ok(Response::new().add_attributes([attr("action", "pause"), attr("sender", info.sender)]))
+}
+
+pub(crate) fn execute_unpause(
+    deps: DepsMut<NeutronQuery>,
+    env: Env,
+    info: MessageInfo,
+) -> ContractResult<Response<NeutronMsg>> {
+    nonpayable(&info, None)?;
+    ensure_owner(deps.as_ref(), &info.sender)?;
+    set_paused(deps.storage, &env, &info.sender, "unpause", false)?;
+    Ok(Response::new().add_attributes([attr("action", "unpause"), attr("sender", info.sender)]))
+}
+
+pub(crate) fn execute_queue_change(
+    deps: DepsMut<NeutronQuery>,
+    env: Env,
+    info: MessageInfo,
+    change: ConfigChange,
+) -> ContractResult<Response<NeutronMsg>> {
+    nonpayable(&info, None)?;
+    ensure_may_change(deps.as_ref(), &change, &info.sender)?;
+    let delay = TIMELOCK_DELAY.may_load(deps.storage)?.unwrap_or_default();
+    let id = NEXT_QUEUED_CHANGE_ID
+        .may_load(deps.storage)?
+        .unwrap_or_default();
+    NEXT_QUEUED_CHANGE_ID.save(deps.storage, &(id + 1))?;
+    let queued_change = QueuedChange {
+        change,
+        proposer: info.sender,
+        eta: env.block.time.plus_seconds(delay),
+    };
+    QUEUED_CHANGES.save(deps.storage, id, &queued_change)?;
+    log_admin_action(
+        deps.storage,
+        &env,
+        &queued_change.proposer,
+        "queue_change",
+        None,
+        Some(to_json_string(&queued_change)?),
+    )?;
+
+    Ok(Response::new().add_attributes([
+        attr("action", "queue_change"),
+        attr("id", id.to_string()),
+        attr("change", queued_change.change.to_string()),
+        attr("proposer", queued_change.proposer),
+        attr("eta", queued_change.eta.to_string()),
+    ]))
+}
+
+pub(crate) fn execute_execute_queued(
+    deps: DepsMut<NeutronQuery>,
+    env: Env,
+    info: MessageInfo,
+    id: u64,
+) -> ContractResult<Response<NeutronMsg>> {
+    let queued_change = QUEUED_CHANGES
+        .may_load(deps.storage, id)?
+        .ok_or(ContractError::QueuedChangeNotFound { id })?;
+    ensure_may_change(deps.as_ref(), &queued_change.change, &info.sender)?;
+    // creation of tokenfactory denom is paid for with fee funds
+    let fee_denom =
+        matches!(queued_change.change, ConfigChange::RegisterAsset { .. }).then_some(FEE_DENOM);
+    nonpayable(&info, fee_denom)?;
+    if env.block.time < queued_change.eta {
+        return Err(ContractError::QueuedChangeNotReady {
+            id,
+            eta: queued_change.eta,
+        });
+    }
+    QUEUED_CHANGES.remove(deps.storage, id);
+
+    Ok(
+        apply_change(deps, &env, &info.sender, queued_change.change)?
+            .add_attribute("queued_change_id", id.to_string()),
+    )
+}
+
+pub(crate) fn execute_cancel_queued(
+    deps: DepsMut<NeutronQuery>,
+    env: Env,
+    info: MessageInfo,
+    id: u64,
+) -> ContractResult<Response<NeutronMsg>> {
+    nonpayable(&info, None)?;
+    ensure_role(deps.as_ref(), Role::Guardian, &info.sender)?;
+    let queued_change = QUEUED_CHANGES
+        .may_load(deps.storage, id)?
+        .ok_or(ContractError::QueuedChangeNotFound { id })?;
+    QUEUED_CHANGES.remove(deps.storage, id);
+    log_admin_action(
+        deps.storage,
+        &env,
+        &info.sender,
+        "cancel_queued",
+        Some(to_json_string(&queued_change)?),
+        None,
+    )?;
+
+    Ok(Response::new().add_attributes([
+        attr("action", "cancel_queued"),
+        attr("id", id.to_string()),
+        attr("change", queued_change.change.to_string()),
+    ]))
+}
+
+/// Makes a change which could have been queued right away, which is only allowed while
+/// there is no timelock delay
+fn execute_change_directly(
+    deps: DepsMut<NeutronQuery>,
+    env: &Env,
+    sender: &Addr,
+    change: ConfigChange,
+) -> ContractResult<Response<NeutronMsg>> {
+    ensure_may_change(deps.as_ref(), &change, sender)?;
+    if TIMELOCK_DELAY.may_load(deps.storage)?.unwrap_or_default() > 0 {
+        return Err(ContractError::TimelockActive {
+            change: change.to_string(),
+        });
+    }
+    apply_change(deps, env, sender, change)
+}
+
+fn apply_change(
+    deps: DepsMut<NeutronQuery>,
+    env: &Env,
+    actor: &Addr,
+    change: ConfigChange,
+) -> ContractResult<Response<NeutronMsg>> {
+    match change {
+        ConfigChange::RegisterAsset {
+            canonical_subdenom,
+            bridged_denoms,
+        } => {
+            ensure_not_migrated(deps.as_ref())?;
+            let create_denom_submsg =
+                register_asset(deps.storage, &canonical_subdenom, &bridged_denoms)?;
+            let asset = ASSETS.load(deps.storage, &canonical_subdenom)?;
+            log_admin_action(
+                deps.storage,
+                env,
+                actor,
+                "register_asset",
+                None,
+                Some(to_json_string(&asset)?),
+            )?;
+
+            Ok(Response::new()
+                .add_submessage(create_denom_submsg)
+                .add_attributes([
+                    attr("action", "register_asset"),
+                    attr("canonical_subdenom", canonical_subdenom),
+                    attr("bridged_denoms", bridged_denoms.join(",")),
+                ]))
+        }
+        ConfigChange::AddBridgedDenom {
+            canonical_subdenom,
+            bridged_denom,
+        } => {
+            ensure_not_migrated(deps.as_ref())?;
+            let mut asset = ASSETS
+                .may_load(deps.storage, &canonical_subdenom)?
+                .ok_or_else(|| ContractError::AssetNotRegistered {
+                    subdenom: canonical_subdenom.clone(),
+                })?;
+            validate_bridged_denom(&bridged_denom, "bridged_denom")?;
+            register_bridged_denom(deps.storage, &bridged_denom, &canonical_subdenom)?;
+            let old_bridged_denoms = asset.bridged_denoms.clone();
+            asset.bridged_denoms.push(bridged_denom.clone());
+            ASSETS.save(deps.storage, &canonical_subdenom, &asset)?;
+            log_admin_action(
+                deps.storage,
+                env,
+                actor,
+                "add_bridged_denom",
+                Some(to_json_string(&old_bridged_denoms)?),
+                Some(to_json_string(&asset.bridged_denoms)?),
+            )?;
+
+            Ok(Response::new().add_attributes([
+                attr("action", "add_bridged_denom"),
+                attr("canonical_subdenom", canonical_subdenom),
+                attr("bridged_denom", bridged_denom),
+            ]))
+        }
+        ConfigChange::SetBridgeStatus {
+            bridged_denom,
+            status,
+        } => {
+            ensure_not_migrated(deps.as_ref())?;
+            let mut bridge = BRIDGES
+                .may_load(deps.storage, &bridged_denom)?
+                .ok_or_else(|| ContractError::BridgedDenomNotRegistered {
+                    denom: bridged_denom.clone(),
+                })?;
+            let old_status = bridge.status;
+            bridge.status = status;
+            BRIDGES.save(deps.storage, &bridged_denom, &bridge)?;
+            log_admin_action(
+                deps.storage,
+                env,
+                actor,
+                "set_bridge_status",
+                Some(to_json_string(&old_status)?),
+                Some(to_json_string(&status)?),
+            )?;
+
+            Ok(Response::new().add_attributes([
+                attr("action", "set_bridge_status"),
+                attr("bridged_denom", bridged_denom),
+                attr("old_status", old_status.to_string()),
+                attr("new_status", status.to_string()),
+            ]))
+        }
+        ConfigChange::SetRoutingPolicy {
+            canonical_subdenom,
+            routing_policy,
+        } => {
+            ensure_not_migrated(deps.as_ref())?;
+            let mut asset = ASSETS
+                .may_load(deps.storage, &canonical_subdenom)?
+                .ok_or_else(|| ContractError::AssetNotRegistered {
+                    subdenom: canonical_subdenom.clone(),
+                })?;
+            let old_routing_policy = asset.routing_policy.clone();
+            asset.routing_policy = routing_policy;
+            ASSETS.save(deps.storage, &canonical_subdenom, &asset)?;
+            log_admin_action(
+                deps.storage,
+                env,
+                actor,
+                "set_routing_policy",
+                Some(to_json_string(&old_routing_policy)?),
+                Some(to_json_string(&asset.routing_policy)?),
+            )?;
+
+            Ok(Response::new().add_attributes([
+                attr("action", "set_routing_policy"),
+                attr("canonical_subdenom", canonical_subdenom),
+                attr("routing_policy", asset.routing_policy.to_string()),
+            ]))
+        }
+        ConfigChange::SetGmpConfig {
+            sender,
+            channel,
+            gateway,
+            gas_service,
+            bridged_denoms,
+        } => {
+            let mut subdenoms = Vec::with_capacity(bridged_denoms.len());
+            for bridged_denom in &bridged_denoms {
+                let subdenom = BRIDGES
+                    .may_load(deps.storage, bridged_denom)?
+                    .ok_or_else(|| ContractError::BridgedDenomNotRegistered {
+                        denom: bridged_denom.clone(),
+                    })?
+                    .canonical_subdenom;
+                if subdenoms.contains(&subdenom) {
+                    return Err(ContractError::MultipleGmpBridgedDenoms { subdenom });
+                }
+                subdenoms.push(subdenom);
             }
+            let gmp_config = GmpConfig {
+                sender: deps.api.addr_validate(&sender)?,
+                channel,
+                gateway,
+                gas_service,
+                bridged_denoms,
+            };
+            let old_gmp_config = GMP_CONFIG.may_load(deps.storage)?;
+            GMP_CONFIG.save(deps.storage, &gmp_config)?;
+            log_admin_action(
+                deps.storage,
+                env,
+                actor,
+                "set_gmp_config",
+                old_gmp_config.as_ref().map(to_json_string).transpose()?,
+                Some(to_json_string(&gmp_config)?),
+            )?;
+
+            Ok(Response::new().add_attributes([
+                attr("action", "set_gmp_config"),
+                attr("sender", gmp_config.sender),
+                attr("channel", gmp_config.channel),
+                attr("gateway", gmp_config.gateway),
+                attr("gas_service", gmp_config.gas_service),
+                attr("bridged_denoms", gmp_config.bridged_denoms.join(",")),
+            ]))
+        }
+        ConfigChange::SetExchangeRateSource {
+            source_chain,
+            source_address,
+        } => {
+            let source = ExchangeRateSource {
+                source_chain,
+                source_address: validate_evm_address(&source_address)?,
+            };
+            let old_source = EXCHANGE_RATE_SOURCE.may_load(deps.storage)?;
+            EXCHANGE_RATE_SOURCE.save(deps.storage, &source)?;
+            log_admin_action(
+                deps.storage,
+                env,
+                actor,
+                "set_exchange_rate_source",
+                old_source.as_ref().map(to_json_string).transpose()?,
+                Some(to_json_string(&source)?),
+            )?;
+
+            Ok(Response::new().add_attributes([
+                attr("action", "set_exchange_rate_source"),
+                attr("source_chain", source.source_chain),
+                attr("source_address", source.source_address),
+            ]))
+        }
+        ConfigChange::SetAmountBounds {
+            bridged_denom,
+            min_amount,
+            max_amount,
+        } => {
+            if let (Some(min_amount), Some(max_amount)) = (min_amount, max_amount) {
+                if min_amount > max_amount {
+                    return Err(ContractError::InvalidAmountBounds {
+                        min_amount,
+                        max_amount,
+                    });
+                }
+            }
+            let mut bridge = BRIDGES
+                .may_load(deps.storage, &bridged_denom)?
+                .ok_or_else(|| ContractError::BridgedDenomNotRegistered {
+                    denom: bridged_denom.clone(),
+                })?;
+            let old_bounds = (bridge.min_amount, bridge.max_amount);
+            bridge.min_amount = min_amount;
+            bridge.max_amount = max_amount;
+            BRIDGES.save(deps.storage, &bridged_denom, &bridge)?;
+            log_admin_action(
+                deps.storage,
+                env,
+                actor,
+                "set_amount_bounds",
+                Some(to_json_string(&old_bounds)?),
+                Some(to_json_string(&(min_amount, max_amount))?),
+            )?;
+
+            let display =
+                |bound: Option<Uint128>| bound.map_or("none".to_string(), |b| b.to_string());
+            Ok(Response::new().add_attributes([
+                attr("action", "set_amount_bounds"),
+                attr("bridged_denom", bridged_denom),
+                attr("min_amount", display(min_amount)),
+                attr("max_amount", display(max_amount)),
+            ]))
+        }
+        ConfigChange::SetWithdrawalQueue {
+            canonical_subdenom,
+            config,
+        } => {
+            if !ASSETS.has(deps.storage, &canonical_subdenom) {
+                return Err(ContractError::AssetNotRegistered {
+                    subdenom: canonical_subdenom,
+                });
+            }
+            let old_config = WITHDRAWAL_QUEUES.may_load(deps.storage, &canonical_subdenom)?;
+            match &config {
+                Some(config) => {
+                    WITHDRAWAL_QUEUES.save(deps.storage, &canonical_subdenom, config)?
+                }
+                None => WITHDRAWAL_QUEUES.remove(deps.storage, &canonical_subdenom),
+            }
+            log_admin_action(
+                deps.storage,
+                env,
+                actor,
+                "set_withdrawal_queue",
+                old_config.as_ref().map(to_json_string).transpose()?,
+                config.as_ref().map(to_json_string).transpose()?,
+            )?;
+
+            let mut response = Response::new().add_attributes([
+                attr("action", "set_withdrawal_queue"),
+                attr("canonical_subdenom", canonical_subdenom),
+            ]);
+            if let Some(config) = config {
+                response = response.add_attributes([
+                    attr("threshold", config.threshold),
+                    attr("delay", config.delay.to_string()),
+                ]);
+            }
+            Ok(response)
+        }
+        ConfigChange::SetTimelockDelay { delay } => {
+            let old_delay = TIMELOCK_DELAY.may_load(deps.storage)?.unwrap_or_default();
+            TIMELOCK_DELAY.save(deps.storage, &delay)?;
+            log_admin_action(
+                deps.storage,
+                env,
+                actor,
+                "set_timelock_delay",
+                Some(to_json_string(&old_delay)?),
+                Some(to_json_string(&delay)?),
+            )?;
+            Ok(Response::new().add_attributes([
+                attr("action", "set_timelock_delay"),
+                attr("delay", delay.to_string()),
+            ]))
+        }
+    }
+}
+
+fn set_paused(
+    storage: &mut dyn Storage,
+    env: &Env,
+    actor: &Addr,
+    action: &str,
+    paused: bool,
+) -> StdResult<()> {
+    let old_paused = PAUSED.may_load(storage)?.unwrap_or_default();
+    if paused {
+        PAUSED.save(storage, &true)?;
+    } else {
+        PAUSED.remove(storage);
+    }
+    log_admin_action(
+        storage,
+        env,
+        actor,
+        action,
+        Some(to_json_string(&old_paused)?),
+        Some(to_json_string(&paused)?),
+    )
+}
+
+/// Appends a privileged action to [`ADMIN_LOG`], values are expected to be JSON encoded
+pub(crate) fn log_admin_action(
+    storage: &mut dyn Storage,
+    env: &Env,
+    actor: &Addr,
+    action: &str,
+    old_value: Option<String>,
+    new_value: Option<String>,
+) -> StdResult<()> {
+    let id = ADMIN_LOG_LENGTH.may_load(storage)?.unwrap_or_default();
+    ADMIN_LOG.save(
+        storage,
+        id,
+        &AdminAction {
+            actor: actor.clone(),
+            height: env.block.height,
+            time: env.block.time,
+            action: action.to_string(),
+            old_value,
+            new_value,
+        },
+    )?;
+    ADMIN_LOG_LENGTH.save(storage, &(id + 1))
+}
+
+/// Validates and stores a new asset, returning a submessage which creates its tokenfactory denom.
+/// Until the reply to this submessage is handled, `canonical_denom` of the asset stores just
+/// a plain subdenom.
+pub(crate) fn register_asset(
+    storage: &mut dyn Storage,
+    canonical_subdenom: &str,
+    bridged_denoms: &[String],
+) -> ContractResult<SubMsg<NeutronMsg>> {
+    validate_canonical_subdenom(canonical_subdenom)?;
+    if ASSETS.has(storage, canonical_subdenom) {
+        return Err(ContractError::AssetAlreadyRegistered {
+            subdenom: canonical_subdenom.to_string(),
+        });
+    }
+    if bridged_denoms.is_empty() {
+        return Err(ContractError::NoBridgedDenoms {});
+    }
+    for bridged_denom in bridged_denoms {
+        validate_bridged_denom(bridged_denom, "bridged_denoms")?;
+        register_bridged_denom(storage, bridged_denom, canonical_subdenom)?;
+    }
+
+    ASSETS.save(
+        storage,
+        canonical_subdenom,
+        &Asset {
+            bridged_denoms: bridged_denoms.to_vec(),
+            canonical_denom: canonical_subdenom.to_string(),
+            routing_policy: RoutingPolicy::default(),
+        },
+    )?;
+    CANONICAL_DENOMS.save(storage, canonical_subdenom, &canonical_subdenom.to_string())?;
+    PENDING_ASSET.save(storage, &canonical_subdenom.to_string())?;
+
+    let create_denom_msg = NeutronMsg::submit_create_denom(canonical_subdenom);
+    Ok(SubMsg::reply_on_success(
+        create_denom_msg,
+        CREATE_DENOM_REPLY_ID,
+    ))
+}
+
+/// Moves config of a contract version which supported a single asset into the registry of
+/// assets, and sets the owner it lacked. Reserves held by such a contract back canonical supply
+/// as a whole, so they are recorded as its expected balance.
+pub(crate) fn migrate_legacy_config(
+    deps: DepsMut<NeutronQuery>,
+    env: &Env,
+    legacy: LegacyConfig,
+    owner: Addr,
+) -> ContractResult<Response> {
+    // canonical denom has a form of `factory/{contract_address}/{subdenom}`, subdenom may
+    // contain slashes itself
+    let canonical_subdenom = legacy
+        .canonical_denom
+        .strip_prefix(&format!("factory/{}/", env.contract.address))
+        .filter(|subdenom| !subdenom.is_empty())
+        .ok_or_else(|| ContractError::ForeignLegacyDenom {
+            denom: legacy.canonical_denom.clone(),
+        })?
+        .to_string();
+    register_bridged_denom(deps.storage, &legacy.bridged_denom, &canonical_subdenom)?;
+    ASSETS.save(
+        deps.storage,
+        &canonical_subdenom,
+        &Asset {
+            bridged_denoms: vec![legacy.bridged_denom.clone()],
+            canonical_denom: legacy.canonical_denom.clone(),
+            routing_policy: RoutingPolicy::default(),
+        },
+    )?;
+    CANONICAL_DENOMS.save(deps.storage, &legacy.canonical_denom, &canonical_subdenom)?;
+
+    let supply = deps.querier.query_supply(&legacy.canonical_denom)?.amount;
+    increase_expected_balance(deps.storage, &legacy.bridged_denom, supply)?;
+
+    let config = Config { owner };
+    CONFIG.save(deps.storage, &config)?;
+    log_admin_action(
+        deps.storage,
+        env,
+        &env.contract.address,
+        "migrate",
+        Some(to_json_string(&legacy)?),
+        Some(to_json_string(&config)?),
+    )?;
+
+    Ok(Response::new().add_attributes([
+        attr("bridged_denom", legacy.bridged_denom),
+        attr("canonical_subdenom", canonical_subdenom),
+        attr("owner", config.owner),
+    ]))
+}
+
+/// Decides which bridged reserves are going to pay out `amount` of burned canonical funds.
+/// Returned coins are sorted by denom, so they can be sent with a single [`BankMsg::Send`].
+fn route_burn(
+    deps: Deps<NeutronQuery>,
+    env: &Env,
+    canonical_subdenom: &str,
+    asset: &Asset,
+    amount: Uint128,
+    preferred_bridged_denom: Option<String>,
+) -> ContractResult<Vec<Coin>> {
+    if let Some(bridged_denom) = preferred_bridged_denom {
+        if !asset.bridged_denoms.contains(&bridged_denom) {
+            return Err(ContractError::BridgedDenomNotInAsset {
+                denom: bridged_denom,
+                subdenom: canonical_subdenom.to_string(),
+            });
+        }
+        let bridge = BRIDGES.load(deps.storage, &bridged_denom)?;
+        if bridge.status == BridgeStatus::Disabled {
+            return Err(ContractError::BridgeDisabled {
+                denom: bridged_denom,
+            });
+        }
+        ensure_within_bounds(&bridge, &bridged_denom, amount)?;
+        let reserve = available_reserve(deps, env, &bridged_denom)?;
+        if reserve.amount < amount {
+            return Err(ContractError::InsufficientReserves {
+                denom: bridged_denom,
+                available: reserve.amount,
+                requested: amount,
+            });
+        }
+        return Ok(vec![coin(amount.u128(), bridged_denom)]);
+    }
+
+    let mut reserves = vec![];
+    for bridged_denom in &asset.bridged_denoms {
+        // burn-only bridges are still drained, only disabled ones are skipped
+        if BRIDGES.load(deps.storage, bridged_denom)?.status == BridgeStatus::Disabled {
+            continue;
+        }
+        reserves.push(available_reserve(deps, env, bridged_denom)?);
+    }
+    if asset.routing_policy == RoutingPolicy::LargestReserve {
+        // sort is stable, so reserves of equal size are still drained in order of priority
+        reserves.sort_by(|a, b| b.amount.cmp(&a.amount));
+    }
+
+    let mut payout = vec![];
+    let mut remaining = amount;
+    for reserve in &reserves {
+        if remaining.is_zero() {
+            break;
+        }
+        let take = reserve.amount.min(remaining);
+        if !take.is_zero() {
+            payout.push(coin(take.u128(), &reserve.denom));
+            remaining -= take;
+        }
+    }
+    if !remaining.is_zero() {
+        // the last bridge in routing order is the one which could not cover the rest,
+        // if every bridge is disabled, the first one of the asset is reported
+        let denom = reserves
+            .last()
+            .map(|reserve| &reserve.denom)
+            .or(asset.bridged_denoms.first())
+            .unwrap_or(&asset.canonical_denom);
+        return Err(ContractError::InsufficientReserves {
+            denom: denom.clone(),
+            available: amount - remaining,
+            requested: amount,
+        });
+    }
+
+    // every bridge paying out a part of the burn has to accept its own share
+    for bridged_funds in &payout {
+        let bridge = BRIDGES.load(deps.storage, &bridged_funds.denom)?;
+        ensure_within_bounds(&bridge, &bridged_funds.denom, bridged_funds.amount)?;
+    }
+
+    payout.sort_by(|a, b| a.denom.cmp(&b.denom));
+    Ok(payout)
+}
+
+fn validate_attribution(referral: Option<&str>, memo: Option<&str>) -> ContractResult<()> {
+    if let Some(referral) = referral {
+        validate_referral(referral)?;
+    }
+    if let Some(memo) = memo {
+        validate_memo(memo)?;
+    }
+    Ok(())
+}
+
+/// Reports `referral` and `memo` of a mint or burn, and updates stats of the referral code
+/// with `update`
+fn attribute(
+    storage: &mut dyn Storage,
+    mut response: Response<NeutronMsg>,
+    referral: Option<String>,
+    memo: Option<String>,
+    canonical_denom: &str,
+    update: impl FnOnce(&mut ReferralStats) -> StdResult<()>,
+) -> ContractResult<Response<NeutronMsg>> {
+    if let Some(referral) = referral {
+        let mut stats = REFERRAL_STATS
+            .may_load(storage, (&referral, canonical_denom))?
+            .unwrap_or_default();
+        update(&mut stats)?;
+        REFERRAL_STATS.save(storage, (&referral, canonical_denom), &stats)?;
+        response = response.add_attribute("referral", referral);
+    }
+    if let Some(memo) = memo {
+        response = response.add_attribute("memo", memo);
+    }
+    Ok(response)
+}
+
+/// Returns bridged reserves which can pay out new burns, that is the balance of the contract
+/// without payouts of queued withdrawals
+fn available_reserve(
+    deps: Deps<NeutronQuery>,
+    env: &Env,
+    bridged_denom: &str,
+) -> ContractResult<Coin> {
+    let mut reserve = deps
+        .querier
+        .query_balance(&env.contract.address, bridged_denom)?;
+    let queued = QUEUED_PAYOUTS
+        .may_load(deps.storage, bridged_denom)?
+        .unwrap_or_default();
+    reserve.amount = reserve.amount.saturating_sub(queued);
+    Ok(reserve)
+}
+
+/// Returns how much of `balance` the contract holds on behalf of users, and how much of it
+/// is surplus. Surplus of a bridged denom is additionally capped by the amount by which reserves
+/// of its asset exceed canonical supply and queued withdrawals, so that it never includes
+/// backing reserves, even if [`EXPECTED_BALANCES`] falls behind.
+pub(crate) fn surplus(
+    deps: Deps<NeutronQuery>,
+    env: &Env,
+    balance: &Coin,
+) -> ContractResult<(Uint128, Uint128)> {
+    let expected = EXPECTED_BALANCES
+        .may_load(deps.storage, &balance.denom)?
+        .unwrap_or_default();
+    let mut surplus = balance.amount.saturating_sub(expected);
+    if let Some(bridge) = BRIDGES.may_load(deps.storage, &balance.denom)? {
+        let asset = ASSETS.load(deps.storage, &bridge.canonical_subdenom)?;
+        let mut backing = deps.querier.query_supply(&asset.canonical_denom)?.amount;
+        let mut reserves = Uint128::zero();
+        for bridged_denom in &asset.bridged_denoms {
+            reserves = reserves.checked_add(
+                deps.querier
+                    .query_balance(&env.contract.address, bridged_denom)?
+                    .amount,
+            )?;
+            backing = backing.checked_add(
+                QUEUED_PAYOUTS
+                    .may_load(deps.storage, bridged_denom)?
+                    .unwrap_or_default(),
+            )?;
+        }
+        surplus = surplus.min(reserves.saturating_sub(backing));
+    }
+    Ok((expected, surplus))
+}
+
+/// Records that the contract holds `amount` more of `denom` on behalf of users
+pub(crate) fn increase_expected_balance(
+    storage: &mut dyn Storage,
+    denom: &str,
+    amount: Uint128,
+) -> StdResult<()> {
+    EXPECTED_BALANCES.update(storage, denom, |expected| -> StdResult<_> {
+        Ok(expected.unwrap_or_default().checked_add(amount)?)
+    })?;
+    Ok(())
+}
+
+/// Records that `amount` of `denom` held on behalf of users has left the contract
+fn decrease_expected_balance(
+    storage: &mut dyn Storage,
+    denom: &str,
+    amount: Uint128,
+) -> StdResult<()> {
+    // funds received before expected balances were tracked are not accounted for
+    let expected = EXPECTED_BALANCES
+        .may_load(storage, denom)?
+        .unwrap_or_default()
+        .saturating_sub(amount);
+    EXPECTED_BALANCES.save(storage, denom, &expected)
+}
+
+fn decrease_expected_balances(storage: &mut dyn Storage, funds: &[Coin]) -> StdResult<()> {
+    for coin in funds {
+        decrease_expected_balance(storage, &coin.denom, coin.amount)?;
+    }
+    Ok(())
+}
+
+/// Returns the moment a burn of `amount` canonical funds of an asset can be paid out at, if it
+/// is large enough to wait in the withdrawal queue of the asset
+fn withdrawal_release_at(
+    storage: &dyn Storage,
+    env: &Env,
+    canonical_subdenom: &str,
+    amount: Uint128,
+) -> StdResult<Option<Timestamp>> {
+    Ok(WITHDRAWAL_QUEUES
+        .may_load(storage, canonical_subdenom)?
+        .filter(|queue| amount > queue.threshold)
+        .map(|queue| env.block.time.plus_seconds(queue.delay)))
+}
+
+/// Stores a withdrawal, reserving its payout in [`QUEUED_PAYOUTS`], and returns its id
+fn queue_withdrawal(storage: &mut dyn Storage, withdrawal: &Withdrawal) -> StdResult<u64> {
+    for bridged_funds in &withdrawal.payout {
+        QUEUED_PAYOUTS.update(storage, &bridged_funds.denom, |queued| -> StdResult<_> {
+            Ok(queued
+                .unwrap_or_default()
+                .checked_add(bridged_funds.amount)?)
+        })?;
+    }
+    let id = NEXT_WITHDRAWAL_ID.may_load(storage)?.unwrap_or_default();
+    NEXT_WITHDRAWAL_ID.save(storage, &(id + 1))?;
+    WITHDRAWALS.save(storage, id, withdrawal)?;
+    RECEIVER_WITHDRAWALS.save(storage, (&withdrawal.receiver, id), &())?;
+    Ok(id)
+}
+
+/// Removes a queued withdrawal, releasing its payout from [`QUEUED_PAYOUTS`]
+fn take_withdrawal(storage: &mut dyn Storage, id: u64) -> ContractResult<Withdrawal> {
+    let withdrawal = WITHDRAWALS
+        .may_load(storage, id)?
+        .ok_or(ContractError::WithdrawalNotFound { id })?;
+    WITHDRAWALS.remove(storage, id);
+    RECEIVER_WITHDRAWALS.remove(storage, (&withdrawal.receiver, id));
+    for bridged_funds in &withdrawal.payout {
+        let queued = QUEUED_PAYOUTS.load(storage, &bridged_funds.denom)?;
+        QUEUED_PAYOUTS.save(
+            storage,
+            &bridged_funds.denom,
+            &queued.checked_sub(bridged_funds.amount)?,
+        )?;
+    }
+    Ok(withdrawal)
+}
+
+fn release_withdrawal(
+    deps: DepsMut<NeutronQuery>,
+    env: &Env,
+    id: u64,
+    withdrawal: Withdrawal,
+    action: &str,
+) -> ContractResult<Response<NeutronMsg>> {
+    decrease_expected_balances(deps.storage, &withdrawal.payout)?;
+    let response = Response::new().add_attributes([
+        attr("action", action),
+        attr("id", id.to_string()),
+        attr("amount", withdrawal.amount),
+        attr("receiver", &withdrawal.receiver),
+    ]);
+    let Some(evm_transfer) = withdrawal.evm_transfer else {
+        return Ok(response.add_message(BankMsg::Send {
+            to_address: withdrawal.receiver.to_string(),
+            amount: withdrawal.payout,
+        }));
+    };
+    let gmp_config = GMP_CONFIG
+        .may_load(deps.storage)?
+        .ok_or(ContractError::GmpNotConfigured {})?;
+    let [payout] = withdrawal
+        .payout
+        .try_into()
+        .expect("withdrawal to EVM chain is paid out in a single bridged denom");
+    let transfer_msg = send_to_evm(
+        deps,
+        env,
+        &gmp_config,
+        &withdrawal.sender,
+        &withdrawal.canonical_denom,
+        payout,
+        &evm_transfer,
+    )?;
+    Ok(response.add_submessage(transfer_msg))
+}
+
+/// Sends `payout` of canonical funds burned by `sender` to an EVM chain over Axelar, the transfer
+/// is tracked once its submessage replies
+fn send_to_evm(
+    deps: DepsMut<NeutronQuery>,
+    env: &Env,
+    gmp_config: &GmpConfig,
+    sender: &Addr,
+    canonical_denom: &str,
+    payout: Coin,
+    evm_transfer: &EvmTransfer,
+) -> ContractResult<SubMsg<NeutronMsg>> {
+    let memo = GmpMessage {
+        destination_chain: evm_transfer.destination_chain.clone(),
+        destination_address: evm_transfer.evm_receiver.clone(),
+        payload: None,
+        type_: GMP_TYPE_TOKEN_TRANSFER,
+        fee: (!evm_transfer.gas_fee.is_zero()).then(|| GmpFee {
+            amount: evm_transfer.gas_fee.to_string(),
+            recipient: gmp_config.gas_service.clone(),
         }),
-        _ => Err(ContractError::ExtraFunds {}),
+    };
+    let transfer_msg = transfer_to_axelar(deps.as_ref(), env, gmp_config, payout.clone(), &memo)?;
+    PENDING_TRANSFER.save(
+        deps.storage,
+        &OutboundTransfer {
+            sender: sender.clone(),
+            canonical_denom: canonical_denom.to_string(),
+            bridged_denom: payout.denom,
+            amount: payout.amount,
+            destination_chain: evm_transfer.destination_chain.clone(),
+            evm_receiver: evm_transfer.evm_receiver.clone(),
+        },
+    )?;
+    Ok(SubMsg::reply_on_success(transfer_msg, BURN_TO_EVM_REPLY_ID))
+}
+
+/// Remembers idempotency key of a mint by `sender`, failing if it has already been processed and
+/// has not expired yet
+fn process_idempotency_key(
+    storage: &mut dyn Storage,
+    now: Timestamp,
+    sender: &Addr,
+    key: &str,
+) -> ContractResult<()> {
+    if key.is_empty() {
+        return Err(ContractError::InvalidIdempotencyKey {
+            reason: "key should not be empty".to_string(),
+        });
+    }
+    if key.len() > MAX_IDEMPOTENCY_KEY_LENGTH {
+        return Err(ContractError::InvalidIdempotencyKey {
+            reason: format!("key should not be longer than {MAX_IDEMPOTENCY_KEY_LENGTH} bytes"),
+        });
+    }
+
+    // an expired key could have not been pruned yet, in which case it is just overwritten
+    if let Some(expires_at) = IDEMPOTENCY_KEYS.may_load(storage, (sender, key))? {
+        if expires_at > now {
+            return Err(ContractError::DuplicateIdempotencyKey {
+                key: key.to_string(),
+            });
+        }
+        IDEMPOTENCY_KEY_EXPIRATIONS.remove(storage, (expires_at.seconds(), sender, key));
+    }
+
+    let expires_at = now.plus_seconds(IDEMPOTENCY_KEY_TTL);
+    IDEMPOTENCY_KEYS.save(storage, (sender, key), &expires_at)?;
+    IDEMPOTENCY_KEY_EXPIRATIONS.save(storage, (expires_at.seconds(), sender, key), &())?;
+    Ok(())
+}
+
+/// Removes at most `limit` idempotency keys which have expired by `now`,
+/// returning the number of removed keys
+pub(crate) fn prune_idempotency_keys(
+    storage: &mut dyn Storage,
+    now: Timestamp,
+    limit: usize,
+) -> ContractResult<u64> {
+    let expired = IDEMPOTENCY_KEY_EXPIRATIONS
+        .keys(
+            storage,
+            None,
+            // empty strings are the smallest keys, so this bound covers everything up to `now`
+            Some(Bound::exclusive((
+                now.seconds() + 1,
+                &Addr::unchecked(""),
+                "",
+            ))),
+            Order::Ascending,
+        )
+        .take(limit)
+        .collect::<StdResult<Vec<_>>>()?;
+    for (expires_at, sender, key) in &expired {
+        IDEMPOTENCY_KEY_EXPIRATIONS.remove(storage, (*expires_at, sender, key));
+        IDEMPOTENCY_KEYS.remove(storage, (sender, key));
+    }
+    Ok(expired.len() as u64)
+}
+
+/// Builds the message which EVM account has to sign in order to claim its pending funds.
+/// It commits to the amount and to the nonce of the account, so that a signature can not be
+/// replayed against funds which become pending later.
+pub(crate) fn claim_message(
+    env: &Env,
+    bridged_denom: &str,
+    claim: &PendingClaim,
+    receiver: &str,
+    nonce: u64,
+) -> String {
+    format!(
+        "Claim {}{} of {bridged_denom} pending in Lido Satellite {} on {} to {receiver}, nonce {nonce}",
+        claim.amount, claim.canonical_denom, env.contract.address, env.block.chain_id,
+    )
+}
+
+/// Removes a pending claim and bumps the claim nonce of its EVM account,
+/// returning the claim along with the nonce it had to be authorized with
+fn take_pending_claim(
+    storage: &mut dyn Storage,
+    evm_address: &str,
+    bridged_denom: &str,
+) -> ContractResult<(PendingClaim, u64)> {
+    let claim = PENDING_CLAIMS
+        .may_load(storage, (evm_address, bridged_denom))?
+        .ok_or_else(|| ContractError::PendingClaimNotFound {
+            evm_address: evm_address.to_string(),
+            bridged_denom: bridged_denom.to_string(),
+        })?;
+    PENDING_CLAIMS.remove(storage, (evm_address, bridged_denom));
+    let nonce = CLAIM_NONCES
+        .may_load(storage, evm_address)?
+        .unwrap_or_default();
+    CLAIM_NONCES.save(storage, evm_address, &(nonce + 1))?;
+    Ok((claim, nonce))
+}
+
+/// Computes the digest which burn intent owner has to sign, see [`BurnIntentSignDoc`]
+pub(crate) fn burn_intent_digest(env: &Env, intent: &BurnIntent) -> ContractResult<Vec<u8>> {
+    let sign_doc = BurnIntentSignDoc {
+        chain_id: env.block.chain_id.clone(),
+        contract: env.contract.address.to_string(),
+        intent: intent.clone(),
+    };
+    Ok(Sha256::digest(to_json_vec(&sign_doc)?).to_vec())
+}
+
+fn take_intent_deposit(
+    storage: &mut dyn Storage,
+    owner: &Addr,
+    denom: &str,
+    amount: Uint128,
+) -> ContractResult<()> {
+    let deposit = INTENT_DEPOSITS
+        .may_load(storage, (owner, denom))?
+        .unwrap_or_default();
+    if deposit < amount {
+        return Err(ContractError::InsufficientIntentDeposit {
+            denom: denom.to_string(),
+            available: deposit,
+            requested: amount,
+        });
+    }
+    let remaining = deposit - amount;
+    if remaining.is_zero() {
+        INTENT_DEPOSITS.remove(storage, (owner, denom));
+    } else {
+        INTENT_DEPOSITS.save(storage, (owner, denom), &remaining)?;
+    }
+    Ok(())
+}
+
+/// Splits `payout` into two parts, the second one containing exactly `fee` funds.
+/// Both parts stay sorted by denom.
+fn split_payout(payout: Vec<Coin>, fee: Uint128) -> (Vec<Coin>, Vec<Coin>) {
+    let mut remaining_fee = fee;
+    let mut rest = vec![];
+    let mut fees = vec![];
+    for fund in payout {
+        let take = fund.amount.min(remaining_fee);
+        remaining_fee -= take;
+        if !take.is_zero() {
+            fees.push(coin(take.u128(), &fund.denom));
+        }
+        if fund.amount > take {
+            rest.push(coin((fund.amount - take).u128(), fund.denom));
+        }
+    }
+    (rest, fees)
+}
+
+fn register_bridged_denom(
+    storage: &mut dyn Storage,
+    bridged_denom: &str,
+    canonical_subdenom: &str,
+) -> ContractResult<()> {
+    if BRIDGES.has(storage, bridged_denom) {
+        return Err(ContractError::BridgedDenomAlreadyRegistered {
+            denom: bridged_denom.to_string(),
+        });
+    }
+    BRIDGES.save(
+        storage,
+        bridged_denom,
+        &Bridge {
+            canonical_subdenom: canonical_subdenom.to_string(),
+            status: BridgeStatus::default(),
+            min_amount: None,
+            max_amount: None,
+        },
+    )?;
+    Ok(())
+}
+
+fn ensure_owner(deps: Deps<NeutronQuery>, sender: &Addr) -> ContractResult<()> {
+    if CONFIG.load(deps.storage)?.owner != sender {
+        return Err(ContractError::Unauthorized {});
+    }
+    Ok(())
+}
+
+/// Checks that `sender` holds `role`, the owner implicitly holds all roles
+fn ensure_role(deps: Deps<NeutronQuery>, role: Role, sender: &Addr) -> ContractResult<()> {
+    if CONFIG.load(deps.storage)?.owner == sender
+        || ROLES.has(deps.storage, (&role.to_string(), sender))
+    {
+        return Ok(());
+    }
+    Err(match role {
+        Role::Guardian => ContractError::NotGuardian {},
+        Role::FeeManager => ContractError::NotFeeManager {},
+        Role::BridgeManager => ContractError::NotBridgeManager {},
+    })
+}
+
+/// Checks that `sender` is allowed to make `change`, either directly or through the queue
+fn ensure_may_change(
+    deps: Deps<NeutronQuery>,
+    change: &ConfigChange,
+    sender: &Addr,
+) -> ContractResult<()> {
+    match change {
+        ConfigChange::RegisterAsset { .. }
+        | ConfigChange::AddBridgedDenom { .. }
+        | ConfigChange::SetBridgeStatus { .. }
+        | ConfigChange::SetRoutingPolicy { .. }
+        | ConfigChange::SetAmountBounds { .. } => ensure_role(deps, Role::BridgeManager, sender),
+        ConfigChange::SetGmpConfig { .. }
+        | ConfigChange::SetExchangeRateSource { .. }
+        | ConfigChange::SetWithdrawalQueue { .. }
+        | ConfigChange::SetTimelockDelay { .. } => ensure_owner(deps, sender),
+    }
+}
+
+fn ensure_within_bounds(bridge: &Bridge, denom: &str, amount: Uint128) -> ContractResult<()> {
+    if let Some(min_amount) = bridge.min_amount.filter(|min_amount| amount < *min_amount) {
+        return Err(ContractError::AmountBelowMinimum {
+            denom: denom.to_string(),
+            amount,
+            min_amount,
+        });
+    }
+    if let Some(max_amount) = bridge.max_amount.filter(|max_amount| amount > *max_amount) {
+        return Err(ContractError::AmountAboveMaximum {
+            denom: denom.to_string(),
+            amount,
+            max_amount,
+        });
+    }
+    Ok(())
+}
+
+fn ensure_not_paused(deps: Deps<NeutronQuery>) -> ContractResult<()> {
+    if PAUSED.may_load(deps.storage)?.unwrap_or_default() {
+        return Err(ContractError::Paused {});
+    }
+    Ok(())
+}
+
+fn ensure_not_migrated(deps: Deps<NeutronQuery>) -> ContractResult<()> {
+    match SUCCESSOR.may_load(deps.storage)? {
+        Some(successor) => Err(ContractError::Migrated {
+            successor: successor.to_string(),
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Rejects funds attached to an execute message which does not consume them, except for
+/// `fee_denom` if given. Such funds would otherwise silently stay in contract.
+fn nonpayable(info: &MessageInfo, fee_denom: Option<&str>) -> ContractResult<()> {
+    match info
+        .funds
+        .iter()
+        .find(|fund| Some(fund.denom.as_str()) != fee_denom)
+    {
+        Some(fund) => Err(ContractError::UnexpectedFunds {
+            denom: fund.denom.clone(),
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Funds attached to an execute message, sorted out by [`parse_funds`]
+pub struct ParsedFunds<'a, T> {
+    /// Coin which denom is present in the registry, along with the registry entry
+    pub asset: Option<(&'a Coin, T)>,
+    /// Coin of the fee denom, it stays in contract
+    pub fee: Option<&'a Coin>,
+}
+
+/// Sorts out `funds` attached to an execute message which accepts a single coin of a denom present
+/// in `registry` and, optionally, a coin of `fee_denom`. Any other coin is rejected.
+pub fn parse_funds<'a, T>(
+    storage: &dyn Storage,
+    funds: &'a [Coin],
+    registry: Map<&str, T>,
+    fee_denom: Option<&str>,
+) -> ContractResult<ParsedFunds<'a, T>>
+where
+    T: Serialize + DeserializeOwned,
+{
+    let mut parsed = ParsedFunds {
+        asset: None,
+        fee: None,
+    };
+    for (i, fund) in funds.iter().enumerate() {
+        if funds[..i].iter().any(|other| other.denom == fund.denom) {
+            return Err(ContractError::DuplicateFunds {
+                denom: fund.denom.clone(),
+            });
+        }
+        if fee_denom == Some(fund.denom.as_str()) {
+            parsed.fee = Some(fund);
+            continue;
+        }
+        match registry.may_load(storage, &fund.denom)? {
+            Some(entry) if parsed.asset.is_none() => parsed.asset = Some((fund, entry)),
+            Some(_) => return Err(ContractError::ExtraFunds {}),
+            None => {
+                return Err(ContractError::UnexpectedFunds {
+                    denom: fund.denom.clone(),
+                })
+            }
+        }
     }
+    Ok(parsed)
 }
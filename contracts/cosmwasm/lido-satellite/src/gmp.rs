@@ -0,0 +1,154 @@
+use crate::{state::GmpConfig, ContractError, ContractResult};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{to_json_string, Api, Coin, Deps, Env, Uint256};
+use neutron_sdk::{
+    bindings::{
+        msg::{IbcFee, NeutronMsg},
+        query::NeutronQuery,
+    },
+    query::min_ibc_fee::query_min_ibc_fee,
+    sudo::msg::RequestPacketTimeoutHeight,
+};
+use sha3::{Digest, Keccak256};
+
+/// Axelar GMP message type which carries only a payload, transferred token pays for gas
+pub const GMP_TYPE_GENERAL_MESSAGE: i64 = 1;
+
+/// Axelar GMP message type which carries only a token transfer, without any payload
+pub const GMP_TYPE_TOKEN_TRANSFER: i64 = 3;
+
+/// Outgoing IBC transfers time out after this number of seconds
+pub const IBC_TRANSFER_TIMEOUT: u64 = 60 * 60;
+
+/// Memo of an IBC transfer to Axelar, which instructs it to route transferred funds
+/// (and optionally a payload) to a contract or an account on an EVM chain
+#[cw_serde]
+pub struct GmpMessage {
+    pub destination_chain: String,
+    pub destination_address: String,
+    pub payload: Option<Vec<u8>>,
+    #[serde(rename = "type")]
+    pub type_: i64,
+    /// Gas payment for execution on the EVM chain
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fee: Option<GmpFee>,
+}
+
+#[cw_serde]
+pub struct GmpFee {
+    pub amount: String,
+    /// Axelar gas service account
+    pub recipient: String,
+}
+
+/// Validates an EVM address of form `0x` followed by 40 hexadecimal characters,
+/// returning it in lowercase
+pub fn validate_evm_address(address: &str) -> ContractResult<String> {
+    let invalid_address = |reason: &str| {
+        Err(ContractError::InvalidEvmAddress {
+            address: address.to_string(),
+            reason: reason.to_string(),
+        })
+    };
+
+    if address.len() != 42 {
+        return invalid_address("expected length of 42 chars");
+    }
+    if !address.starts_with("0x") {
+        return invalid_address("expected prefix '0x'");
+    }
+    if !address.chars().skip(2).all(|c| c.is_ascii_hexdigit()) {
+        return invalid_address("invalid hex characters");
+    }
+    Ok(address.to_ascii_lowercase())
+}
+
+/// Computes a hash of `message` the same way Ethereum wallets do for `personal_sign` (EIP-191)
+pub fn eth_personal_message_hash(message: &str) -> [u8; 32] {
+    let mut hasher = Keccak256::new();
+    hasher.update(format!("\x19Ethereum Signed Message:\n{}", message.len()));
+    hasher.update(message);
+    hasher.finalize().into()
+}
+
+/// Recovers an EVM address (in lowercase) which produced a 65 bytes `r || s || v` signature
+/// of `hash`. Both `v` conventions (0/1 and 27/28) are accepted.
+pub fn recover_evm_address(api: &dyn Api, hash: &[u8], signature: &[u8]) -> ContractResult<String> {
+    if signature.len() != 65 {
+        return Err(ContractError::InvalidSignature {});
+    }
+    let recovery_param = match signature[64] {
+        v @ (0 | 1) => v,
+        v @ (27 | 28) => v - 27,
+        _ => return Err(ContractError::InvalidSignature {}),
+    };
+    let pubkey = api
+        .secp256k1_recover_pubkey(hash, &signature[..64], recovery_param)
+        .map_err(|_| ContractError::InvalidSignature {})?;
+    // uncompressed public key is prefixed with 0x04, which is not a part of the address
+    let pubkey_hash = Keccak256::digest(&pubkey[1..]);
+    Ok(format!("0x{}", hex::encode(&pubkey_hash[12..])))
+}
+
+/// Builds an IBC transfer of `token` to Axelar, which routes it further to `destination_address`
+/// on `destination_chain`. IBC fees are paid by contract from its own balance.
+pub fn transfer_to_evm(
+    deps: Deps<NeutronQuery>,
+    env: &Env,
+    gmp_config: &GmpConfig,
+    token: Coin,
+    destination_chain: &str,
+    destination_address: &str,
+) -> ContractResult<NeutronMsg> {
+    let memo = GmpMessage {
+        destination_chain: destination_chain.to_string(),
+        destination_address: destination_address.to_string(),
+        payload: None,
+        type_: GMP_TYPE_TOKEN_TRANSFER,
+        fee: None,
+    };
+    transfer_to_axelar(deps, env, gmp_config, token, &memo)
+}
+
+/// Builds an IBC transfer of `token` to Axelar GMP account, carrying `memo` which instructs
+/// Axelar what to do with it. IBC fees are paid by contract from its own balance.
+pub fn transfer_to_axelar(
+    deps: Deps<NeutronQuery>,
+    env: &Env,
+    gmp_config: &GmpConfig,
+    token: Coin,
+    memo: &GmpMessage,
+) -> ContractResult<NeutronMsg> {
+    Ok(NeutronMsg::IbcTransfer {
+        source_port: "transfer".to_string(),
+        source_channel: gmp_config.channel.clone(),
+        token,
+        sender: env.contract.address.to_string(),
+        receiver: gmp_config.gateway.clone(),
+        timeout_height: RequestPacketTimeoutHeight {
+            revision_number: None,
+            revision_height: None,
+        },
+        timeout_timestamp: env.block.time.plus_seconds(IBC_TRANSFER_TIMEOUT).nanos(),
+        memo: to_json_string(memo)?,
+        fee: min_ibc_fee(deps)?,
+    })
+}
+
+/// ABI-encodes a tuple of `uint256` values, the same way `abi.encode` does in Solidity
+pub fn abi_encode_uint256s(values: &[Uint256]) -> Vec<u8> {
+    values
+        .iter()
+        .flat_map(|value| value.to_be_bytes())
+        .collect()
+}
+
+fn min_ibc_fee(deps: Deps<NeutronQuery>) -> ContractResult<IbcFee> {
+    let min_fee = query_min_ibc_fee(deps)?.min_fee;
+    Ok(IbcFee {
+        // receive fee is not supported by Neutron and must be empty
+        recv_fee: vec![],
+        ack_fee: min_fee.ack_fee,
+        timeout_fee: min_fee.timeout_fee,
+    })
+}
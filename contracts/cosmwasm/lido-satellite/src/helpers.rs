@@ -0,0 +1,110 @@
+use crate::msg::{
+    AssetResponse, ConfigResponse, ExchangeRateResponse, ExecuteMsg, HealthResponse, QueryMsg,
+};
+use cosmwasm_schema::{cw_serde, serde::de::DeserializeOwned};
+use cosmwasm_std::{
+    to_json_binary, Addr, Coin, CosmosMsg, CustomQuery, QuerierWrapper, StdResult, Uint128, WasmMsg,
+};
+
+/// Wrapper around the address of a Lido Satellite contract, which builds messages to it
+/// and queries it with typed responses
+#[cw_serde]
+pub struct LidoSatelliteContract(pub Addr);
+
+impl LidoSatelliteContract {
+    pub fn addr(&self) -> Addr {
+        self.0.clone()
+    }
+
+    /// Builds a message executing `msg` with `funds` attached
+    pub fn call<T>(&self, msg: impl Into<ExecuteMsg>, funds: Vec<Coin>) -> StdResult<CosmosMsg<T>> {
+        Ok(WasmMsg::Execute {
+            contract_addr: self.addr().into(),
+            msg: to_json_binary(&msg.into())?,
+            funds,
+        }
+        .into())
+    }
+
+    /// Builds [`ExecuteMsg::Mint`] of canonical funds in exchange for `bridged_funds`,
+    /// minted to `receiver` or to the sender of the message
+    pub fn mint<T>(
+        &self,
+        bridged_funds: Coin,
+        receiver: Option<String>,
+    ) -> StdResult<CosmosMsg<T>> {
+        let msg = ExecuteMsg::Mint {
+            receiver,
+            idempotency_key: None,
+            source_chain: None,
+            source_address: None,
+            evm_sender: None,
+            ibc_hooks_origin: None,
+            referral: None,
+            memo: None,
+        };
+        self.call(msg, vec![bridged_funds])
+    }
+
+    /// Builds [`ExecuteMsg::Burn`] of `canonical_funds`, paying out bridged funds to `receiver`
+    /// or to the sender of the message, preferably in `bridged_denom`
+    pub fn burn<T>(
+        &self,
+        canonical_funds: Coin,
+        receiver: Option<String>,
+        bridged_denom: Option<String>,
+    ) -> StdResult<CosmosMsg<T>> {
+        let msg = ExecuteMsg::Burn {
+            receiver,
+            bridged_denom,
+            referral: None,
+            memo: None,
+        };
+        self.call(msg, vec![canonical_funds])
+    }
+
+    /// Queries the contract with `msg`, deserializing the response into `R`
+    pub fn query<Q: CustomQuery, R: DeserializeOwned>(
+        &self,
+        querier: &QuerierWrapper<Q>,
+        msg: &QueryMsg,
+    ) -> StdResult<R> {
+        querier.query_wasm_smart(self.addr(), msg)
+    }
+
+    pub fn config<Q: CustomQuery>(&self, querier: &QuerierWrapper<Q>) -> StdResult<ConfigResponse> {
+        self.query(querier, &QueryMsg::Config {})
+    }
+
+    pub fn asset<Q: CustomQuery>(
+        &self,
+        querier: &QuerierWrapper<Q>,
+        canonical_subdenom: impl Into<String>,
+    ) -> StdResult<AssetResponse> {
+        self.query(
+            querier,
+            &QueryMsg::Asset {
+                canonical_subdenom: canonical_subdenom.into(),
+            },
+        )
+    }
+
+    pub fn exchange_rate<Q: CustomQuery>(
+        &self,
+        querier: &QuerierWrapper<Q>,
+    ) -> StdResult<ExchangeRateResponse> {
+        self.query(querier, &QueryMsg::ExchangeRate {})
+    }
+
+    pub fn convert_to_steth<Q: CustomQuery>(
+        &self,
+        querier: &QuerierWrapper<Q>,
+        amount: Uint128,
+    ) -> StdResult<Uint128> {
+        self.query(querier, &QueryMsg::ConvertToStEth { amount })
+    }
+
+    pub fn health<Q: CustomQuery>(&self, querier: &QuerierWrapper<Q>) -> StdResult<HealthResponse> {
+        self.query(querier, &QueryMsg::Health {})
+    }
+}
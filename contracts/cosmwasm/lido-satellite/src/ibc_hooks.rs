@@ -0,0 +1,50 @@
+use crate::{ContractError, ContractResult};
+use bech32::{ToBase32, Variant};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Addr;
+use sha2::{Digest, Sha256};
+
+/// Bech32 prefix of Neutron accounts
+pub const NEUTRON_BECH32_PREFIX: &str = "neutron";
+
+/// Prefix ibc-hooks hashes along with channel and original sender to derive intermediate sender
+pub const IBC_HOOKS_SENDER_PREFIX: &str = "ibc-wasm-hook-intermediary";
+
+/// Origin of a contract call made by ibc-hooks on behalf of an account on another chain
+#[cw_serde]
+pub struct IbcHooksOrigin {
+    /// Neutron side of the IBC channel the call came over
+    pub channel: String,
+    /// Account on the counterparty chain which initiated the call
+    pub original_sender: String,
+}
+
+/// Derives the address ibc-hooks executes contract calls from on behalf of `original_sender`,
+/// the same way `DeriveIntermediateSender` of ibc-hooks does
+pub fn derive_intermediate_sender(channel: &str, original_sender: &str) -> ContractResult<String> {
+    let prefix_hash = Sha256::digest(IBC_HOOKS_SENDER_PREFIX);
+    let sender_hash = Sha256::new()
+        .chain_update(prefix_hash)
+        .chain_update(format!("{channel}/{original_sender}"))
+        .finalize();
+    bech32::encode(
+        NEUTRON_BECH32_PREFIX,
+        sender_hash.to_base32(),
+        Variant::Bech32,
+    )
+    .map_err(|err| ContractError::InvalidIbcHooksOrigin {
+        reason: err.to_string(),
+    })
+}
+
+/// Checks that `sender` is the intermediate sender ibc-hooks derives for `origin`
+pub fn verify_ibc_hooks_origin(sender: &Addr, origin: &IbcHooksOrigin) -> ContractResult<()> {
+    let expected = derive_intermediate_sender(&origin.channel, &origin.original_sender)?;
+    if sender.as_str() != expected {
+        return Err(ContractError::IbcHooksSenderMismatch {
+            expected,
+            actual: sender.to_string(),
+        });
+    }
+    Ok(())
+}
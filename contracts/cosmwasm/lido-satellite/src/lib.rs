@@ -6,7 +6,14 @@ pub mod state;
 pub use crate::error::{ContractError, ContractResult};
 
 pub mod execute;
+pub mod gmp;
+#[cfg(any(test, feature = "library"))]
+pub mod helpers;
+pub mod ibc_hooks;
 pub mod query;
+pub mod sudo;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
 
 #[cfg(test)]
 mod tests;
@@ -1,91 +1,587 @@
-use crate::{ContractError, ContractResult};
+use crate::{
+    ibc_hooks::IbcHooksOrigin,
+    state::{
+        AttestationConfig, BridgeStatus, ConfigChange, EscrowBalance, EvmTransfer, GmpConfig,
+        HealthCheck, Role, RoutingPolicy, WithdrawalQueueConfig,
+    },
+    ContractError, ContractResult,
+};
 use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Binary, Coin, Decimal256, Timestamp, Uint128};
+use neutron_sdk::sudo::msg::RequestPacket;
+
+/// For how long, in seconds, idempotency keys of processed mints are remembered
+pub const IDEMPOTENCY_KEY_TTL: u64 = 7 * 24 * 60 * 60;
+
+/// Exchange rate updates read from Ethereum blocks older than this number of seconds are rejected
+pub const MAX_EXCHANGE_RATE_AGE: u64 = 24 * 60 * 60;
+
+/// Exchange rate updates read from Ethereum blocks ahead of Neutron time by more than this number
+/// of seconds are rejected, tolerating clock drift between the chains
+pub const MAX_EXCHANGE_RATE_CLOCK_SKEW: u64 = 5 * 60;
+
+/// Maximum length of an idempotency key, in bytes
+pub const MAX_IDEMPOTENCY_KEY_LENGTH: usize = 128;
+
+/// Maximum length of a referral code, in bytes
+pub const MAX_REFERRAL_LENGTH: usize = 32;
+
+/// Maximum length of a memo, in bytes
+pub const MAX_MEMO_LENGTH: usize = 256;
+
+/// Maximum number of outputs of [`ExecuteMsg::MintBatch`]
+pub const MAX_MINT_BATCH_SIZE: usize = 50;
 
 #[cw_serde]
 pub struct InstantiateMsg {
     /// This denom will be locked on contract's balance. Users are expected to send this
     /// denom with [`ExecuteMsg::Mint`] message in order to receive minted canonical funds.
+    /// Together with `canonical_subdenom` it forms the first asset of the registry,
+    /// more assets can be registered later with [`ExecuteMsg::RegisterAsset`].
     pub bridged_denom: String,
     /// This subdenom will form a canonical denom, minted by contract in exchange for bridged funds
     /// sent by users. Users are expected to send this denom with [`ExecuteMsg::Burn`] message
     /// in order to receive original bridged funds back.
     pub canonical_subdenom: String,
+    /// This address will be able to perform privileged operations, such as migrating contract
+    /// to a successor. By default, it is set to the address instantiating the contract.
+    pub owner: Option<String>,
 }
 
 impl InstantiateMsg {
     pub fn validate(&self) -> ContractResult<()> {
-        if self.bridged_denom.is_empty() {
-            return Err(ContractError::EmptyDenom {
-                kind: "bridged_denom".to_string(),
-            });
-        }
-        Self::validate_ibc_denom(&self.bridged_denom)?;
-
-        if self.canonical_subdenom.is_empty() {
-            return Err(ContractError::EmptyDenom {
-                kind: "canonical_subdenom".to_string(),
-            });
-        }
-        Ok(())
+        validate_bridged_denom(&self.bridged_denom, "bridged_denom")?;
+        validate_canonical_subdenom(&self.canonical_subdenom)
+    }
+}
+
+pub(crate) fn validate_bridged_denom(denom: &str, kind: &str) -> ContractResult<()> {
+    if denom.is_empty() {
+        return Err(ContractError::EmptyDenom {
+            kind: kind.to_string(),
+        });
+    }
+    validate_ibc_denom(denom)
+}
+
+pub(crate) fn validate_canonical_subdenom(subdenom: &str) -> ContractResult<()> {
+    if subdenom.is_empty() {
+        return Err(ContractError::EmptyDenom {
+            kind: "canonical_subdenom".to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Referral codes are non-empty strings of at most [`MAX_REFERRAL_LENGTH`] lowercase letters,
+/// digits, dashes and underscores
+pub fn validate_referral(referral: &str) -> ContractResult<()> {
+    let invalid_referral = |reason: &str| {
+        Err(ContractError::InvalidReferral {
+            referral: referral.to_string(),
+            reason: reason.to_string(),
+        })
+    };
+    if referral.is_empty() {
+        return invalid_referral("must not be empty");
+    }
+    if referral.len() > MAX_REFERRAL_LENGTH {
+        return invalid_referral("too long");
+    }
+    if !referral
+        .chars()
+        .all(|c| matches!(c, 'a'..='z' | '0'..='9' | '-' | '_'))
+    {
+        return invalid_referral("only lowercase letters, digits, '-' and '_' are allowed");
+    }
+    Ok(())
+}
+
+/// Memos are strings of at most [`MAX_MEMO_LENGTH`] printable ASCII characters
+pub fn validate_memo(memo: &str) -> ContractResult<()> {
+    if memo.len() > MAX_MEMO_LENGTH {
+        return Err(ContractError::InvalidMemo {
+            reason: "too long".to_string(),
+        });
+    }
+    if !memo.chars().all(|c| matches!(c, ' '..='~')) {
+        return Err(ContractError::InvalidMemo {
+            reason: "only printable ASCII characters are allowed".to_string(),
+        });
+    }
+    Ok(())
+}
+
+fn validate_ibc_denom(denom: &str) -> ContractResult<()> {
+    let invalid_denom = |reason: &str| {
+        Err(ContractError::InvalidIbcDenom {
+            denom: String::from(denom),
+            reason: reason.to_string(),
+        })
+    };
+
+    // Example IBC denom: ibc/584A4A23736884E0C198FD1EE932455A9357A492A7B94324E4A02B5628687831
+    // Length of this string is `len("ibc/") + 64 /* hex encoded 32 bytes hash */ == 68`
+
+    // Step 1: Validate length
+    if denom.len() != 68 {
+        return invalid_denom("expected length of 68 chars");
+    }
+
+    // Step 2: Validate prefix
+    if !denom.starts_with("ibc/") {
+        return invalid_denom("expected prefix 'ibc/'");
     }
 
-    fn validate_ibc_denom(denom: &str) -> ContractResult<()> {
-        let invalid_denom = |reason: &str| {
-            Err(ContractError::InvalidIbcDenom {
-                denom: String::from(denom),
-                reason: reason.to_string(),
-            })
-        };
-
-        // Example IBC denom: ibc/584A4A23736884E0C198FD1EE932455A9357A492A7B94324E4A02B5628687831
-        // Length of this string is `len("ibc/") + 64 /* hex encoded 32 bytes hash */ == 68`
-
-        // Step 1: Validate length
-        if denom.len() != 68 {
-            return invalid_denom("expected length of 68 chars");
-        }
-
-        // Step 2: Validate prefix
-        if !denom.starts_with("ibc/") {
-            return invalid_denom("expected prefix 'ibc/'");
-        }
-
-        // Step 3: Validate hash
-        if !denom
-            .chars()
-            .skip(4)
-            // c.is_ascii_hexdigit() could have been used here, but it allows lowercase characters
-            .all(|c| matches!(c, '0'..='9' | 'A'..='F'))
-        {
-            return invalid_denom("invalid denom hash");
-        }
-
-        Ok(())
+    // Step 3: Validate hash
+    if !denom
+        .chars()
+        .skip(4)
+        // c.is_ascii_hexdigit() could have been used here, but it allows lowercase characters
+        .all(|c| matches!(c, '0'..='9' | 'A'..='F'))
+    {
+        return invalid_denom("invalid denom hash");
     }
+
+    Ok(())
 }
 
 #[cw_serde]
 pub enum ExecuteMsg {
     /// This method expects users to send bridged funds, which will be locked in contract.
     /// In exchange, users receive canonical funds, which are minted by tokenfactory module.
+    /// Asset to mint is determined by the denom of bridged funds sent.
     Mint {
         /// By default, canonical funds are minted to sender, but they can optionally be minted
         /// to any address specified in this field.
         receiver: Option<String>,
+        /// Optional client-supplied key (e.g. Axelar command id), which makes retried mints
+        /// fail instead of being processed twice. Keys are scoped to the sender of the mint and
+        /// remembered for [`IDEMPOTENCY_KEY_TTL`] seconds.
+        idempotency_key: Option<String>,
+        /// Axelar name of the chain a GMP call originates from. This field is filled by Axelar
+        /// and only taken into account for messages coming from Axelar GMP.
+        source_chain: Option<String>,
+        /// Address of the EVM account a GMP call originates from. This field is filled by Axelar
+        /// and only taken into account for messages coming from Axelar GMP. If `receiver` turns
+        /// out to be invalid, minted funds are kept in contract until this account claims them
+        /// with [`ExecuteMsg::ClaimPending`] or the owner returns them with
+        /// [`ExecuteMsg::ReturnPending`].
+        source_address: Option<String>,
+        /// EVM account which called a helper contract (e.g. GMP Helper) making the GMP call
+        /// on its behalf, filled by the helper. It is only taken into account along with
+        /// `source_address`, and if set, funds kept in contract are claimed by this account
+        /// instead of the helper.
+        evm_sender: Option<String>,
+        /// Channel and original sender of a mint made through ibc-hooks. It is verified against
+        /// sender, and if `receiver` is not set, funds are minted to the original sender.
+        /// If the original sender is not a valid Neutron address, `receiver` must be set.
+        ibc_hooks_origin: Option<IbcHooksOrigin>,
+        /// Code of the partner front-end the mint is attributed to, see [`validate_referral`]
+        referral: Option<String>,
+        /// Free-form note reported in mint events, see [`validate_memo`]
+        memo: Option<String>,
     },
+    /// This method works like [`ExecuteMsg::Mint`], but splits canonical funds minted in exchange
+    /// for bridged funds sent between several receivers. Amounts of outputs must add up to
+    /// the amount of bridged funds sent, there can be at most [`MAX_MINT_BATCH_SIZE`] of them.
+    MintBatch { outputs: Vec<(String, Uint128)> },
     /// This method expects users to send canonical funds, which will be burned.
-    /// In exchange, users receive original bridged funds back.
+    /// In exchange, users receive original bridged funds back. Asset to burn is determined
+    /// by the denom of canonical funds sent.
     Burn {
         /// By default, bridged funds are returned back to sender, but they can optionally be
         /// returned to any address specified in this field.
         receiver: Option<String>,
+        /// By default, bridged funds are taken from reserves according to routing policy of
+        /// the asset, possibly from several reserves at once. If this field is set, the whole
+        /// amount is returned in this bridged denom, or the burn fails if its reserve
+        /// is insufficient.
+        bridged_denom: Option<String>,
+        /// Code of the partner front-end the burn is attributed to, see [`validate_referral`]
+        referral: Option<String>,
+        /// Free-form note reported in burn events, see [`validate_memo`]
+        memo: Option<String>,
+    },
+    /// This method expects users to send canonical funds, which will be kept in contract
+    /// on behalf of sender. Later, they can be burned with [`ExecuteMsg::ExecuteBurnIntent`]
+    /// by anyone holding a burn intent signed by `pubkey`, so that sender does not have to pay
+    /// for gas at the moment of burning.
+    DepositForIntents {
+        /// secp256k1 public key (33 bytes compressed or 65 bytes uncompressed) which will sign
+        /// burn intents. It replaces a public key set by previous deposits, if any.
+        pubkey: Binary,
+    },
+    /// This method returns canonical funds deposited with [`ExecuteMsg::DepositForIntents`]
+    /// back to sender.
+    WithdrawIntentDeposit { denom: String, amount: Uint128 },
+    /// This method burns canonical funds deposited by intent owner, and sends bridged funds
+    /// to receiver, minus a fee which is paid to the relayer submitting this message.
+    ExecuteBurnIntent {
+        intent: BurnIntent,
+        /// secp256k1 signature (64 bytes, `r || s`) of sha256 hash of JSON-serialized
+        /// [`BurnIntentSignDoc`], see [`QueryMsg::BurnIntentDigest`]
+        signature: Binary,
+        /// Fee, in bridged funds, which relayer asks for, it must not exceed
+        /// `intent.max_relayer_fee`
+        relayer_fee: Uint128,
+    },
+    /// This method sends canonical funds, which came over Axelar GMP to an invalid receiver,
+    /// to a new receiver. It has to be authorized by the EVM account which sent them.
+    ClaimPending {
+        evm_address: String,
+        bridged_denom: String,
+        receiver: String,
+        /// 65 bytes `r || s || v` signature of [`QueryMsg::ClaimMessage`], produced by
+        /// `personal_sign` of the EVM account
+        signature: Binary,
+    },
+    /// This method is only available to the owner. It burns canonical funds, which came over
+    /// Axelar GMP to an invalid receiver, and sends bridged funds back to the EVM account
+    /// which sent them.
+    ReturnPending {
+        evm_address: String,
+        bridged_denom: String,
+    },
+    /// This method is only available to the owner. It sets up interaction with Axelar GMP.
+    /// While timelock delay is set, it has to be queued with [`ExecuteMsg::QueueChange`] instead.
+    SetGmpConfig {
+        /// Address on Neutron which executes messages coming from Axelar GMP
+        sender: String,
+        /// Neutron side of the IBC channel to Axelar
+        channel: String,
+        /// Axelar GMP account on Axelar chain
+        gateway: String,
+        /// Axelar gas service account on Axelar chain
+        gas_service: String,
+        /// Registered bridged denoms which arrive over `channel`, at most one per asset.
+        /// [`ExecuteMsg::BurnToEvm`] pays out only from these.
+        bridged_denoms: Vec<String>,
+    },
+    /// This method expects users to send canonical funds, burns them and sends bridged funds
+    /// over IBC to Axelar, which routes them to `evm_receiver` on `destination_chain`.
+    /// Bridged funds are always paid out in the denom of the asset listed in GMP config.
+    /// If the transfer fails, canonical funds are minted back to sender.
+    /// Funds of [`crate::execute::FEE_DENOM`] may be attached to cover IBC fees.
+    BurnToEvm {
+        evm_receiver: String,
+        /// Axelar name of the EVM chain
+        destination_chain: String,
+        /// Part of transferred bridged funds paid to Axelar gas service
+        gas_fee: Uint128,
+    },
+    /// This method sends supply of canonical funds and bridged reserves of the configured asset,
+    /// along with current height and timestamp, to the configured EVM contract over Axelar GMP.
+    /// Exactly one coin of [`crate::execute::FEE_DENOM`] has to be attached, it is transferred
+    /// to Axelar to pay for gas.
+    /// Attestations can not be sent more often than configured.
+    AttestSupply {},
+    /// This method is only available to the owner. It configures [`ExecuteMsg::AttestSupply`].
+    SetAttestationConfig {
+        canonical_subdenom: String,
+        destination_chain: String,
+        destination_address: String,
+        /// Minimum number of seconds between two attestations
+        min_interval: u64,
+    },
+    /// This method is only available to Axelar GMP sender. It stores wstETH to stETH
+    /// exchange rate read on Ethereum by the configured exchange rate source.
+    UpdateExchangeRate {
+        source_chain: String,
+        source_address: String,
+        /// Amount of stETH one wstETH is worth
+        rate: Decimal256,
+        /// Ethereum block the rate has been read at, must be greater than the one of
+        /// the current rate
+        block_number: u64,
+        /// Timestamp of the Ethereum block, in seconds, must not be older than
+        /// [`MAX_EXCHANGE_RATE_AGE`] nor ahead of Neutron time by more than
+        /// [`MAX_EXCHANGE_RATE_CLOCK_SKEW`]
+        block_timestamp: u64,
+    },
+    /// This method is only available to the owner. It sets the EVM contract which is allowed
+    /// to push exchange rate updates with [`ExecuteMsg::UpdateExchangeRate`].
+    /// While timelock delay is set, it has to be queued with [`ExecuteMsg::QueueChange`] instead.
+    SetExchangeRateSource {
+        source_chain: String,
+        source_address: String,
+    },
+    /// This method is only available to bridge managers. It registers a Neutron interchain query
+    /// of the balance of the escrow account backing `bridged_denom` on the counterparty chain,
+    /// replacing the previous query for that denom, if any. Interchain query deposit has to be
+    /// attached as funds of [`crate::execute::FEE_DENOM`].
+    RegisterEscrowQuery {
+        bridged_denom: String,
+        connection_id: String,
+        escrow_address: String,
+        remote_denom: String,
+        update_period: u64,
+    },
+    /// This method is only available to bridge managers. It registers a new asset and creates
+    /// a tokenfactory denom for it, funds of [`crate::execute::FEE_DENOM`] may be attached to pay
+    /// for the denom. While timelock delay is set, it has to be queued with
+    /// [`ExecuteMsg::QueueChange`] instead.
+    RegisterAsset {
+        /// This subdenom will form a canonical denom of the new asset, it must not be used
+        /// by any other asset.
+        canonical_subdenom: String,
+        /// These denoms will be accepted in exchange for canonical funds of the new asset,
+        /// they must not be used by any other asset.
+        bridged_denoms: Vec<String>,
+    },
+    /// This method is only available to bridge managers. It adds one more bridged denom to
+    /// an already registered asset. While timelock delay is set, it has to be queued with
+    /// [`ExecuteMsg::QueueChange`] instead.
+    AddBridgedDenom {
+        canonical_subdenom: String,
+        bridged_denom: String,
+    },
+    /// This method is only available to bridge managers. It changes status of a bridged denom,
+    /// e.g. to stop accepting new mints against a bridge which is being retired. While timelock
+    /// delay is set, it has to be queued with [`ExecuteMsg::QueueChange`] instead.
+    SetBridgeStatus {
+        bridged_denom: String,
+        status: BridgeStatus,
     },
+    /// This method is only available to bridge managers. It sets bounds of amounts which can be
+    /// minted against or paid out by a bridged denom at once, `None` removes a bound.
+    /// While timelock delay is set, it has to be queued with [`ExecuteMsg::QueueChange`] instead.
+    SetAmountBounds {
+        bridged_denom: String,
+        min_amount: Option<Uint128>,
+        max_amount: Option<Uint128>,
+    },
+    /// This method is only available to the owner. It makes burns of more than `threshold`
+    /// canonical funds of an asset wait for `delay` seconds before bridged funds are paid out,
+    /// `None` disables the queue. While timelock delay is set, it has to be queued with
+    /// [`ExecuteMsg::QueueChange`] instead.
+    SetWithdrawalQueue {
+        canonical_subdenom: String,
+        config: Option<WithdrawalQueueConfig>,
+    },
+    /// This method pays out a queued withdrawal to its receiver once it is released.
+    ClaimWithdrawal { id: u64 },
+    /// This method is only available to guardians. It pays out a queued withdrawal right away.
+    ApproveWithdrawal { id: u64 },
+    /// This method is only available to guardians. It drops a queued withdrawal and mints
+    /// burned canonical funds back to its sender.
+    CancelWithdrawal { id: u64 },
+    /// This method is only available to bridge managers. It changes the way burns are routed
+    /// between bridged reserves of an asset when user has no preferred bridged denom.
+    /// While timelock delay is set, it has to be queued with [`ExecuteMsg::QueueChange`] instead.
+    SetRoutingPolicy {
+        canonical_subdenom: String,
+        routing_policy: RoutingPolicy,
+    },
+    /// This method is only available to the owner. It grants `role` to `address`.
+    GrantRole { role: Role, address: String },
+    /// This method is only available to the owner. It revokes `role` from `address`.
+    RevokeRole { role: Role, address: String },
+    /// This method is only available to guardians. It suspends mints and burns until
+    /// the owner calls [`ExecuteMsg::Unpause`].
+    Pause {},
+    /// This method is only available to the owner. It resumes mints and burns.
+    Unpause {},
+    /// This method is available to whoever is allowed to make `change` directly. It queues
+    /// the change, which can be executed with [`ExecuteMsg::ExecuteQueued`] once the timelock
+    /// delay has passed.
+    QueueChange { change: ConfigChange },
+    /// This method is available to whoever is allowed to make the queued change directly.
+    /// It executes the change once its eta has come. Funds of [`crate::execute::FEE_DENOM`] may
+    /// be attached to a queued [`ConfigChange::RegisterAsset`], see [`ExecuteMsg::RegisterAsset`].
+    ExecuteQueued { id: u64 },
+    /// This method is only available to guardians. It drops a queued change.
+    CancelQueued { id: u64 },
+    /// This method is only available to the owner. It sends surplus of `denom`, i.e. funds which
+    /// were sent to the contract directly and are not owed to anyone, to `to`.
+    /// Surplus of [`crate::execute::FEE_DENOM`], i.e. leftover fees, is swept by fee managers
+    /// instead. See [`QueryMsg::Surplus`].
+    SweepSurplus { denom: String, to: String },
+    /// This method is only available to the owner. It hands tokenfactory admin of all canonical
+    /// denoms and all bridged funds locked in contract over to the successor contract. After that,
+    /// both [`ExecuteMsg::Mint`] and [`ExecuteMsg::Burn`] are permanently disabled. It fails while
+    /// any withdrawals are queued, see [`ExecuteMsg::SetWithdrawalQueue`], or any transfers of
+    /// [`ExecuteMsg::BurnToEvm`] are not acknowledged yet.
+    MigrateToSuccessor {
+        /// Address of the contract which is going to replace this one.
+        successor: String,
+    },
+}
+
+#[cw_serde]
+pub struct BurnIntent {
+    /// Address which deposited canonical funds with [`ExecuteMsg::DepositForIntents`]
+    pub owner: String,
+    pub canonical_denom: String,
+    /// Amount of canonical funds to burn, relayer fee included
+    pub amount: Uint128,
+    pub receiver: String,
+    /// Must be equal to the next nonce of owner's intent account
+    pub nonce: u64,
+    /// Intent can not be executed after this moment
+    pub expires_at: Timestamp,
+    pub max_relayer_fee: Uint128,
+}
+
+/// This is what is actually signed by burn intent owner. Chain id and contract address
+/// prevent the same signature from being replayed against another deployment.
+#[cw_serde]
+pub struct BurnIntentSignDoc {
+    pub chain_id: String,
+    pub contract: String,
+    pub intent: BurnIntent,
 }
 
 #[cw_serde]
 pub struct ConfigResponse {
+    pub owner: String,
+    pub paused: bool,
+    /// Number of seconds queued changes have to wait before they can be executed
+    pub timelock_delay: u64,
+    /// Is set once contract has been migrated to a successor, see [`ExecuteMsg::MigrateToSuccessor`]
+    pub successor: Option<String>,
+    pub assets: Vec<AssetResponse>,
+    pub gmp: Option<GmpConfig>,
+    pub attestation: Option<AttestationConfig>,
+    /// Moment the latest supply attestation has been sent at
+    pub last_attestation: Option<Timestamp>,
+}
+
+#[cw_serde]
+pub struct RolesResponse {
+    pub owner: String,
+    pub roles: Vec<RoleResponse>,
+}
+
+#[cw_serde]
+pub struct RoleResponse {
+    pub role: Role,
+    pub holders: Vec<String>,
+}
+
+#[cw_serde]
+pub struct AdminLogEntryResponse {
+    pub id: u64,
+    pub actor: String,
+    pub height: u64,
+    pub time: Timestamp,
+    pub action: String,
+    /// JSON encoded value affected by the action before it
+    pub old_value: Option<String>,
+    /// JSON encoded value affected by the action after it
+    pub new_value: Option<String>,
+}
+
+#[cw_serde]
+pub struct QueuedChangeResponse {
+    pub id: u64,
+    pub change: ConfigChange,
+    pub proposer: String,
+    pub eta: Timestamp,
+}
+
+#[cw_serde]
+pub struct AssetResponse {
+    pub canonical_subdenom: String,
+    pub canonical_denom: String,
+    /// Bridged denoms backing this asset, listed in order of priority
+    pub bridges: Vec<BridgeResponse>,
+    pub routing_policy: RoutingPolicy,
+    pub withdrawal_queue: Option<WithdrawalQueueConfig>,
+}
+
+#[cw_serde]
+pub struct ReferralStatsResponse {
+    pub canonical_denom: String,
+    /// Amount of canonical funds minted by mints attributed to the referral code
+    pub minted: Uint128,
+    /// Amount of canonical funds burned by burns attributed to the referral code
+    pub burned: Uint128,
+    pub mints: u64,
+    pub burns: u64,
+}
+
+#[cw_serde]
+pub struct SurplusResponse {
+    pub denom: String,
+    /// Contract balance of the denom
+    pub balance: Uint128,
+    /// Amount of the denom the contract holds on behalf of users
+    pub expected: Uint128,
+    /// Amount of the denom which can be swept with [`ExecuteMsg::SweepSurplus`]
+    pub surplus: Uint128,
+}
+
+#[cw_serde]
+pub struct WithdrawalResponse {
+    pub id: u64,
+    pub sender: String,
+    pub receiver: String,
+    pub canonical_denom: String,
+    pub amount: Uint128,
+    pub payout: Vec<Coin>,
+    pub release_at: Timestamp,
+    pub evm_transfer: Option<EvmTransfer>,
+}
+
+#[cw_serde]
+pub struct BridgeResponse {
+    pub bridged_denom: String,
+    pub status: BridgeStatus,
+    pub min_amount: Option<Uint128>,
+    pub max_amount: Option<Uint128>,
+}
+
+#[cw_serde]
+pub struct IdempotencyKeyResponse {
+    pub processed: bool,
+    /// Moment after which the key is forgotten, set only if the key has been processed
+    pub expires_at: Option<Timestamp>,
+}
+
+#[cw_serde]
+pub struct PendingClaimResponse {
     pub bridged_denom: String,
+    pub source_chain: String,
     pub canonical_denom: String,
+    pub amount: Uint128,
+}
+
+#[cw_serde]
+pub struct EscrowReserveResponse {
+    pub bridged_denom: String,
+    pub connection_id: String,
+    pub escrow_address: String,
+    pub remote_denom: String,
+    pub query_id: Option<u64>,
+    /// Latest escrow balance on the counterparty chain, is not set until the first query result
+    pub remote_balance: Option<EscrowBalance>,
+    /// Bridged funds locked in this contract
+    pub local_reserves: Uint128,
+    /// Whether remote escrow covers local reserves, is not set until the first query result
+    pub fully_backed: Option<bool>,
+}
+
+#[cw_serde]
+pub struct HealthResponse {
+    /// Is not set until the first health check
+    pub last_check: Option<HealthCheck>,
+}
+
+#[cw_serde]
+pub struct ExchangeRateResponse {
+    /// Amount of stETH one wstETH is worth
+    pub rate: Decimal256,
+    pub block_number: u64,
+    pub block_timestamp: u64,
+    pub updated_at: Timestamp,
+}
+
+#[cw_serde]
+pub struct IntentAccountResponse {
+    pub pubkey: Binary,
+    pub next_nonce: u64,
+    pub deposits: Vec<Coin>,
 }
 
 #[cw_serde]
@@ -93,7 +589,95 @@ pub struct ConfigResponse {
 pub enum QueryMsg {
     #[returns(ConfigResponse)]
     Config {},
+    #[returns(RolesResponse)]
+    Roles {},
+    /// Lists privileged actions in order they have been performed
+    #[returns(Vec<AdminLogEntryResponse>)]
+    AdminLog {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// Lists queued changes in order of their ids
+    #[returns(Vec<QueuedChangeResponse>)]
+    QueuedChanges {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    #[returns(AssetResponse)]
+    Asset { canonical_subdenom: String },
+    /// Returns totals of mints and burns attributed to `referral`, per canonical denom
+    #[returns(Vec<ReferralStatsResponse>)]
+    ReferralStats { referral: String },
+    /// Reports surplus of every denom held by the contract
+    #[returns(Vec<SurplusResponse>)]
+    Surplus {},
+    /// Lists queued withdrawals paying out to `receiver` in order of their ids
+    #[returns(Vec<WithdrawalResponse>)]
+    Withdrawals {
+        receiver: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    #[returns(Vec<PendingClaimResponse>)]
+    PendingClaims { evm_address: String },
+    /// Returns the message which EVM account has to `personal_sign` in order to authorize
+    /// [`ExecuteMsg::ClaimPending`] of its current pending claim. The message covers the amount
+    /// pending and a nonce of the account, so it has to be signed again once either changes.
+    #[returns(String)]
+    ClaimMessage {
+        evm_address: String,
+        bridged_denom: String,
+        receiver: String,
+    },
+    #[returns(ExchangeRateResponse)]
+    ExchangeRate {},
+    #[returns(HealthResponse)]
+    Health {},
+    /// Compares escrow balances on counterparty chains against bridged reserves of this contract
+    #[returns(Vec<EscrowReserveResponse>)]
+    ProofOfReserve {},
+    /// Returns the amount of stETH which `amount` of wstETH is worth, rounded down
+    #[returns(Uint128)]
+    ConvertToStEth { amount: Uint128 },
+    /// Returns whether `key` has been used by mints of `sender`, see [`ExecuteMsg::Mint`]
+    #[returns(IdempotencyKeyResponse)]
+    IdempotencyKey { sender: String, key: String },
+    #[returns(IntentAccountResponse)]
+    IntentAccount { address: String },
+    /// Returns the digest which must be signed to authorize [`ExecuteMsg::ExecuteBurnIntent`]
+    #[returns(Binary)]
+    BurnIntentDigest { intent: BurnIntent },
 }
 
+/// Messages sent to contract by Neutron modules
 #[cw_serde]
-pub struct MigrateMsg {}
+pub enum SudoMsg {
+    /// Expected to be scheduled periodically with Neutron cron module. It checks that every
+    /// asset is fully backed by bridged reserves and prunes expired idempotency keys,
+    /// the result is returned by [`QueryMsg::Health`]. There are no rate-limit windows to roll
+    /// over: amounts are only limited per message by amount bounds of bridges and large burns
+    /// are delayed by the withdrawal queue, neither keeps state which expires with time.
+    HealthCheck {},
+    /// Sent by Neutron interchain queries module once a new result of a KV query is submitted
+    #[serde(rename = "kv_query_result")]
+    KVQueryResult { query_id: u64 },
+    /// Sent by Neutron once an outgoing IBC transfer is acknowledged
+    Response {
+        request: RequestPacket,
+        data: Binary,
+    },
+    /// Sent by Neutron once an outgoing IBC transfer fails
+    Error {
+        request: RequestPacket,
+        details: String,
+    },
+    /// Sent by Neutron once an outgoing IBC transfer times out
+    Timeout { request: RequestPacket },
+}
+
+#[cw_serde]
+pub struct MigrateMsg {
+    /// Owner of the contract, required when migrating from a version which supported
+    /// a single asset and had no owner
+    pub owner: Option<String>,
+}
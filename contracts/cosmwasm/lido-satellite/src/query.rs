@@ -1,11 +1,386 @@
-use crate::{msg::ConfigResponse, state::CONFIG, ContractResult};
-use cosmwasm_std::{to_json_binary, Binary, Deps};
+use crate::{
+    execute::{burn_intent_digest, claim_message, surplus},
+    gmp::validate_evm_address,
+    msg::{
+        AdminLogEntryResponse, AssetResponse, BridgeResponse, BurnIntent, ConfigResponse,
+        EscrowReserveResponse, ExchangeRateResponse, HealthResponse, IdempotencyKeyResponse,
+        IntentAccountResponse, PendingClaimResponse, QueuedChangeResponse, ReferralStatsResponse,
+        RoleResponse, RolesResponse, SurplusResponse, WithdrawalResponse,
+    },
+    state::{
+        Asset, Role, ADMIN_LOG, ASSETS, ATTESTATION_CONFIG, BRIDGES, CLAIM_NONCES, CONFIG,
+        ESCROW_BALANCES, ESCROW_QUERIES, EXCHANGE_RATE, GMP_CONFIG, IDEMPOTENCY_KEYS,
+        INTENT_ACCOUNTS, INTENT_DEPOSITS, LAST_ATTESTATION, LAST_HEALTH_CHECK, PAUSED,
+        PENDING_CLAIMS, QUEUED_CHANGES, RECEIVER_WITHDRAWALS, REFERRAL_STATS, ROLES, SUCCESSOR,
+        TIMELOCK_DELAY, WITHDRAWALS, WITHDRAWAL_QUEUES,
+    },
+    ContractError, ContractResult,
+};
+use cosmwasm_std::{
+    coin, to_json_binary, Binary, Decimal256, Deps, Env, Order, StdResult, Uint128,
+};
+use cw_storage_plus::Bound;
 use neutron_sdk::bindings::query::NeutronQuery;
 
+/// Number of items returned by paginated queries when no limit is given
+const DEFAULT_PAGE_LIMIT: u32 = 10;
+/// Maximum number of items returned by paginated queries
+const MAX_PAGE_LIMIT: u32 = 30;
+
 pub(crate) fn query_config(deps: Deps<NeutronQuery>) -> ContractResult<Binary> {
     let config = CONFIG.load(deps.storage)?;
+    let assets = ASSETS
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .map(|(subdenom, asset)| asset_response(deps, subdenom, asset))
+        .collect::<ContractResult<Vec<_>>>()?;
     Ok(to_json_binary(&ConfigResponse {
-        bridged_denom: config.bridged_denom,
-        canonical_denom: config.canonical_denom,
+        owner: config.owner.to_string(),
+        paused: PAUSED.may_load(deps.storage)?.unwrap_or_default(),
+        timelock_delay: TIMELOCK_DELAY.may_load(deps.storage)?.unwrap_or_default(),
+        successor: SUCCESSOR
+            .may_load(deps.storage)?
+            .map(|successor| successor.to_string()),
+        assets,
+        gmp: GMP_CONFIG.may_load(deps.storage)?,
+        attestation: ATTESTATION_CONFIG.may_load(deps.storage)?,
+        last_attestation: LAST_ATTESTATION.may_load(deps.storage)?,
+    })?)
+}
+
+pub(crate) fn query_roles(deps: Deps<NeutronQuery>) -> ContractResult<Binary> {
+    let config = CONFIG.load(deps.storage)?;
+    let roles = Role::ALL
+        .into_iter()
+        .map(|role| {
+            let holders = ROLES
+                .prefix(&role.to_string())
+                .keys(deps.storage, None, None, Order::Ascending)
+                .map(|holder| holder.map(|holder| holder.to_string()))
+                .collect::<StdResult<Vec<_>>>()?;
+            Ok(RoleResponse { role, holders })
+        })
+        .collect::<ContractResult<Vec<_>>>()?;
+    Ok(to_json_binary(&RolesResponse {
+        owner: config.owner.to_string(),
+        roles,
     })?)
 }
+
+pub(crate) fn query_admin_log(
+    deps: Deps<NeutronQuery>,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> ContractResult<Binary> {
+    let limit = limit.unwrap_or(DEFAULT_PAGE_LIMIT).min(MAX_PAGE_LIMIT) as usize;
+    let entries = ADMIN_LOG
+        .range(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .map(|item| {
+            item.map(|(id, action)| AdminLogEntryResponse {
+                id,
+                actor: action.actor.to_string(),
+                height: action.height,
+                time: action.time,
+                action: action.action,
+                old_value: action.old_value,
+                new_value: action.new_value,
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+    Ok(to_json_binary(&entries)?)
+}
+
+pub(crate) fn query_queued_changes(
+    deps: Deps<NeutronQuery>,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> ContractResult<Binary> {
+    let limit = limit.unwrap_or(DEFAULT_PAGE_LIMIT).min(MAX_PAGE_LIMIT) as usize;
+    let changes = QUEUED_CHANGES
+        .range(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .map(|item| {
+            item.map(|(id, queued_change)| QueuedChangeResponse {
+                id,
+                change: queued_change.change,
+                proposer: queued_change.proposer.to_string(),
+                eta: queued_change.eta,
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+    Ok(to_json_binary(&changes)?)
+}
+
+pub(crate) fn query_asset(
+    deps: Deps<NeutronQuery>,
+    canonical_subdenom: String,
+) -> ContractResult<Binary> {
+    let asset = ASSETS
+        .may_load(deps.storage, &canonical_subdenom)?
+        .ok_or_else(|| ContractError::AssetNotRegistered {
+            subdenom: canonical_subdenom.clone(),
+        })?;
+    Ok(to_json_binary(&asset_response(
+        deps,
+        canonical_subdenom,
+        asset,
+    )?)?)
+}
+
+pub(crate) fn query_referral_stats(
+    deps: Deps<NeutronQuery>,
+    referral: String,
+) -> ContractResult<Binary> {
+    let stats = REFERRAL_STATS
+        .prefix(&referral)
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| {
+            item.map(|(canonical_denom, stats)| ReferralStatsResponse {
+                canonical_denom,
+                minted: stats.minted,
+                burned: stats.burned,
+                mints: stats.mints,
+                burns: stats.burns,
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+    Ok(to_json_binary(&stats)?)
+}
+
+pub(crate) fn query_surplus(deps: Deps<NeutronQuery>, env: Env) -> ContractResult<Binary> {
+    let balances = deps.querier.query_all_balances(&env.contract.address)?;
+    let mut response = Vec::with_capacity(balances.len());
+    for balance in balances {
+        let (expected, surplus) = surplus(deps, &env, &balance)?;
+        response.push(SurplusResponse {
+            denom: balance.denom,
+            balance: balance.amount,
+            expected,
+            surplus,
+        });
+    }
+    Ok(to_json_binary(&response)?)
+}
+
+pub(crate) fn query_withdrawals(
+    deps: Deps<NeutronQuery>,
+    receiver: String,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> ContractResult<Binary> {
+    let receiver = deps.api.addr_validate(&receiver)?;
+    let limit = limit.unwrap_or(DEFAULT_PAGE_LIMIT).min(MAX_PAGE_LIMIT) as usize;
+    let withdrawals = RECEIVER_WITHDRAWALS
+        .prefix(&receiver)
+        .keys(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .map(|id| {
+            let id = id?;
+            let withdrawal = WITHDRAWALS.load(deps.storage, id)?;
+            Ok(WithdrawalResponse {
+                id,
+                sender: withdrawal.sender.to_string(),
+                receiver: withdrawal.receiver.to_string(),
+                canonical_denom: withdrawal.canonical_denom,
+                amount: withdrawal.amount,
+                payout: withdrawal.payout,
+                release_at: withdrawal.release_at,
+                evm_transfer: withdrawal.evm_transfer,
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+    Ok(to_json_binary(&withdrawals)?)
+}
+
+pub(crate) fn query_pending_claims(
+    deps: Deps<NeutronQuery>,
+    evm_address: String,
+) -> ContractResult<Binary> {
+    let evm_address = validate_evm_address(&evm_address)?;
+    let claims = PENDING_CLAIMS
+        .prefix(&evm_address)
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| {
+            item.map(|(bridged_denom, claim)| PendingClaimResponse {
+                bridged_denom,
+                source_chain: claim.source_chain,
+                canonical_denom: claim.canonical_denom,
+                amount: claim.amount,
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(to_json_binary(&claims)?)
+}
+
+pub(crate) fn query_claim_message(
+    deps: Deps<NeutronQuery>,
+    env: Env,
+    evm_address: String,
+    bridged_denom: String,
+    receiver: String,
+) -> ContractResult<Binary> {
+    let evm_address = validate_evm_address(&evm_address)?;
+    let claim = PENDING_CLAIMS
+        .may_load(deps.storage, (&evm_address, &bridged_denom))?
+        .ok_or_else(|| ContractError::PendingClaimNotFound {
+            evm_address: evm_address.clone(),
+            bridged_denom: bridged_denom.clone(),
+        })?;
+    let nonce = CLAIM_NONCES
+        .may_load(deps.storage, &evm_address)?
+        .unwrap_or_default();
+    Ok(to_json_binary(&claim_message(
+        &env,
+        &bridged_denom,
+        &claim,
+        &receiver,
+        nonce,
+    ))?)
+}
+
+pub(crate) fn query_exchange_rate(deps: Deps<NeutronQuery>) -> ContractResult<Binary> {
+    let exchange_rate = EXCHANGE_RATE
+        .may_load(deps.storage)?
+        .ok_or(ContractError::ExchangeRateNotSet {})?;
+    Ok(to_json_binary(&ExchangeRateResponse {
+        rate: exchange_rate.rate,
+        block_number: exchange_rate.block_number,
+        block_timestamp: exchange_rate.block_timestamp,
+        updated_at: exchange_rate.updated_at,
+    })?)
+}
+
+pub(crate) fn query_convert_to_steth(
+    deps: Deps<NeutronQuery>,
+    amount: Uint128,
+) -> ContractResult<Binary> {
+    let exchange_rate = EXCHANGE_RATE
+        .may_load(deps.storage)?
+        .ok_or(ContractError::ExchangeRateNotSet {})?;
+    let steth_amount = Decimal256::from_ratio(amount, 1u128)
+        .checked_mul(exchange_rate.rate)?
+        .to_uint_floor();
+    Ok(to_json_binary(&Uint128::try_from(steth_amount)?)?)
+}
+
+pub(crate) fn query_health(deps: Deps<NeutronQuery>) -> ContractResult<Binary> {
+    Ok(to_json_binary(&HealthResponse {
+        last_check: LAST_HEALTH_CHECK.may_load(deps.storage)?,
+    })?)
+}
+
+pub(crate) fn query_proof_of_reserve(deps: Deps<NeutronQuery>, env: Env) -> ContractResult<Binary> {
+    let escrow_queries = ESCROW_QUERIES
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut reserves = Vec::with_capacity(escrow_queries.len());
+    for (bridged_denom, escrow_query) in escrow_queries {
+        let local_reserves = deps
+            .querier
+            .query_balance(&env.contract.address, &bridged_denom)?
+            .amount;
+        let remote_balance = ESCROW_BALANCES.may_load(deps.storage, &bridged_denom)?;
+        reserves.push(EscrowReserveResponse {
+            fully_backed: remote_balance
+                .as_ref()
+                .map(|remote_balance| remote_balance.amount >= local_reserves),
+            bridged_denom,
+            connection_id: escrow_query.connection_id,
+            escrow_address: escrow_query.escrow_address,
+            remote_denom: escrow_query.remote_denom,
+            query_id: escrow_query.query_id,
+            remote_balance,
+            local_reserves,
+        });
+    }
+    Ok(to_json_binary(&reserves)?)
+}
+
+pub(crate) fn query_idempotency_key(
+    deps: Deps<NeutronQuery>,
+    env: Env,
+    sender: String,
+    key: String,
+) -> ContractResult<Binary> {
+    let sender = deps.api.addr_validate(&sender)?;
+    // expired keys may still be in storage if they have not been pruned yet
+    let expires_at = IDEMPOTENCY_KEYS
+        .may_load(deps.storage, (&sender, &key))?
+        .filter(|expires_at| *expires_at > env.block.time);
+    Ok(to_json_binary(&IdempotencyKeyResponse {
+        processed: expires_at.is_some(),
+        expires_at,
+    })?)
+}
+
+pub(crate) fn query_intent_account(
+    deps: Deps<NeutronQuery>,
+    address: String,
+) -> ContractResult<Binary> {
+    let address = deps.api.addr_validate(&address)?;
+    let account = INTENT_ACCOUNTS
+        .may_load(deps.storage, &address)?
+        .ok_or_else(|| ContractError::IntentAccountNotFound {
+            address: address.to_string(),
+        })?;
+    let deposits = INTENT_DEPOSITS
+        .prefix(&address)
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(denom, amount)| coin(amount.u128(), denom)))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(to_json_binary(&IntentAccountResponse {
+        pubkey: account.pubkey,
+        next_nonce: account.next_nonce,
+        deposits,
+    })?)
+}
+
+pub(crate) fn query_burn_intent_digest(env: Env, intent: BurnIntent) -> ContractResult<Binary> {
+    Ok(to_json_binary(&Binary::from(burn_intent_digest(
+        &env, &intent,
+    )?))?)
+}
+
+fn asset_response(
+    deps: Deps<NeutronQuery>,
+    canonical_subdenom: String,
+    asset: Asset,
+) -> ContractResult<AssetResponse> {
+    let bridges = asset
+        .bridged_denoms
+        .into_iter()
+        .map(|bridged_denom| {
+            let bridge = BRIDGES.load(deps.storage, &bridged_denom)?;
+            Ok(BridgeResponse {
+                bridged_denom,
+                status: bridge.status,
+                min_amount: bridge.min_amount,
+                max_amount: bridge.max_amount,
+            })
+        })
+        .collect::<ContractResult<Vec<_>>>()?;
+    Ok(AssetResponse {
+        canonical_denom: asset.canonical_denom,
+        bridges,
+        routing_policy: asset.routing_policy,
+        withdrawal_queue: WITHDRAWAL_QUEUES.may_load(deps.storage, &canonical_subdenom)?,
+        canonical_subdenom,
+    })
+}
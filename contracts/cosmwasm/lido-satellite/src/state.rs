@@ -1,10 +1,463 @@
 use cosmwasm_schema::cw_serde;
-use cw_storage_plus::Item;
+use cosmwasm_std::{Addr, Binary, Coin, Decimal256, Timestamp, Uint128};
+use cw_storage_plus::{Item, Map};
+use std::fmt;
 
 #[cw_serde]
 pub struct Config {
+    pub owner: Addr,
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+
+/// Config of contract versions which supported a single asset, stored under the same key as
+/// [`CONFIG`]. It is moved into the registry of assets by `migrate`.
+#[cw_serde]
+pub struct LegacyConfig {
     pub bridged_denom: String,
     pub canonical_denom: String,
 }
 
-pub const CONFIG: Item<Config> = Item::new("config");
+pub const LEGACY_CONFIG: Item<LegacyConfig> = Item::new("config");
+
+#[cw_serde]
+pub struct Asset {
+    /// Bridged denoms which can be exchanged for canonical denom of this asset,
+    /// listed in order of priority
+    pub bridged_denoms: Vec<String>,
+    pub canonical_denom: String,
+    pub routing_policy: RoutingPolicy,
+}
+
+/// Decides which bridged reserves pay out a burn when user has no preference
+#[cw_serde]
+#[derive(Default)]
+pub enum RoutingPolicy {
+    /// Reserves are drained in order of priority of bridged denoms
+    #[default]
+    Priority,
+    /// Reserves are drained starting with the largest one
+    LargestReserve,
+}
+
+impl fmt::Display for RoutingPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RoutingPolicy::Priority => write!(f, "priority"),
+            RoutingPolicy::LargestReserve => write!(f, "largest_reserve"),
+        }
+    }
+}
+
+/// Roles which can be granted by the owner. The owner itself implicitly holds all of them.
+#[cw_serde]
+#[derive(Copy)]
+pub enum Role {
+    /// Can pause the contract, but not unpause it
+    Guardian,
+    /// Collects leftover fees, see [`crate::msg::ExecuteMsg::SweepSurplus`]
+    FeeManager,
+    /// Manages the registry of assets and bridged denoms
+    BridgeManager,
+}
+
+impl Role {
+    pub const ALL: [Role; 3] = [Role::Guardian, Role::FeeManager, Role::BridgeManager];
+}
+
+impl fmt::Display for Role {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Role::Guardian => write!(f, "guardian"),
+            Role::FeeManager => write!(f, "fee_manager"),
+            Role::BridgeManager => write!(f, "bridge_manager"),
+        }
+    }
+}
+
+/// Holders of roles, keyed by role name and address
+pub const ROLES: Map<(&str, &Addr), ()> = Map::new("roles");
+
+/// While set, mints and burns are suspended
+pub const PAUSED: Item<bool> = Item::new("paused");
+
+/// Risky configuration change, which has to be queued and can only be executed once
+/// the timelock delay has passed
+#[cw_serde]
+pub enum ConfigChange {
+    RegisterAsset {
+        canonical_subdenom: String,
+        bridged_denoms: Vec<String>,
+    },
+    AddBridgedDenom {
+        canonical_subdenom: String,
+        bridged_denom: String,
+    },
+    SetBridgeStatus {
+        bridged_denom: String,
+        status: BridgeStatus,
+    },
+    SetRoutingPolicy {
+        canonical_subdenom: String,
+        routing_policy: RoutingPolicy,
+    },
+    SetGmpConfig {
+        sender: String,
+        channel: String,
+        gateway: String,
+        gas_service: String,
+        bridged_denoms: Vec<String>,
+    },
+    SetExchangeRateSource {
+        source_chain: String,
+        source_address: String,
+    },
+    SetAmountBounds {
+        bridged_denom: String,
+        min_amount: Option<Uint128>,
+        max_amount: Option<Uint128>,
+    },
+    SetWithdrawalQueue {
+        canonical_subdenom: String,
+        /// `None` pays out all burns of the asset instantly
+        config: Option<WithdrawalQueueConfig>,
+    },
+    SetTimelockDelay {
+        delay: u64,
+    },
+}
+
+impl fmt::Display for ConfigChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigChange::RegisterAsset { .. } => write!(f, "register_asset"),
+            ConfigChange::AddBridgedDenom { .. } => write!(f, "add_bridged_denom"),
+            ConfigChange::SetBridgeStatus { .. } => write!(f, "set_bridge_status"),
+            ConfigChange::SetRoutingPolicy { .. } => write!(f, "set_routing_policy"),
+            ConfigChange::SetGmpConfig { .. } => write!(f, "set_gmp_config"),
+            ConfigChange::SetExchangeRateSource { .. } => write!(f, "set_exchange_rate_source"),
+            ConfigChange::SetAmountBounds { .. } => write!(f, "set_amount_bounds"),
+            ConfigChange::SetWithdrawalQueue { .. } => write!(f, "set_withdrawal_queue"),
+            ConfigChange::SetTimelockDelay { .. } => write!(f, "set_timelock_delay"),
+        }
+    }
+}
+
+#[cw_serde]
+pub struct QueuedChange {
+    pub change: ConfigChange,
+    pub proposer: Addr,
+    /// Moment the change can be executed at
+    pub eta: Timestamp,
+}
+
+/// Number of seconds queued changes have to wait before they can be executed. While it is not
+/// zero, changes listed in [`ConfigChange`] can not be made directly.
+pub const TIMELOCK_DELAY: Item<u64> = Item::new("timelock_delay");
+
+/// Changes waiting for the timelock delay to pass, keyed by id
+pub const QUEUED_CHANGES: Map<u64, QueuedChange> = Map::new("queued_changes");
+
+/// Id of the next queued change
+pub const NEXT_QUEUED_CHANGE_ID: Item<u64> = Item::new("next_queued_change_id");
+
+/// Registry of assets, keyed by canonical subdenom
+pub const ASSETS: Map<&str, Asset> = Map::new("assets");
+
+#[cw_serde]
+pub struct Bridge {
+    /// Canonical subdenom of an asset this bridged denom belongs to
+    pub canonical_subdenom: String,
+    pub status: BridgeStatus,
+    /// Smallest amount which can be minted against or paid out by this bridge at once
+    pub min_amount: Option<Uint128>,
+    /// Largest amount which can be minted against or paid out by this bridge at once
+    pub max_amount: Option<Uint128>,
+}
+
+#[cw_serde]
+#[derive(Copy, Default)]
+pub enum BridgeStatus {
+    /// Bridged denom can be both minted against and paid out on burn
+    #[default]
+    Active,
+    /// Bridge is being sunset: no new mints are accepted, but its reserve can still be drained
+    /// by burns
+    BurnOnly,
+    /// Neither mints nor burns touch this bridged denom
+    Disabled,
+}
+
+impl fmt::Display for BridgeStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BridgeStatus::Active => write!(f, "active"),
+            BridgeStatus::BurnOnly => write!(f, "burn_only"),
+            BridgeStatus::Disabled => write!(f, "disabled"),
+        }
+    }
+}
+
+/// Registry of bridged denoms, keyed by bridged denom
+pub const BRIDGES: Map<&str, Bridge> = Map::new("bridges");
+
+/// Reverse index from canonical denom to canonical subdenom of an asset it belongs to
+pub const CANONICAL_DENOMS: Map<&str, String> = Map::new("canonical_denoms");
+
+/// Canonical subdenom of an asset which tokenfactory denom is being created right now.
+/// It is consumed by the reply handler.
+pub const PENDING_ASSET: Item<String> = Item::new("pending_asset");
+
+#[cw_serde]
+pub struct IntentAccount {
+    /// secp256k1 public key which signs burn intents of this account
+    pub pubkey: Binary,
+    /// Nonce which the next burn intent of this account must have
+    pub next_nonce: u64,
+}
+
+/// Accounts which are allowed to burn their deposits with signed intents, keyed by depositor
+pub const INTENT_ACCOUNTS: Map<&Addr, IntentAccount> = Map::new("intent_accounts");
+
+/// Canonical funds deposited to be burned later with signed intents,
+/// keyed by depositor and canonical denom
+pub const INTENT_DEPOSITS: Map<(&Addr, &str), Uint128> = Map::new("intent_deposits");
+
+/// Idempotency keys of processed mints along with the moment they expire at, keyed by sender
+/// and key. Keys are scoped to their sender, so that nobody can take up a key of someone else.
+pub const IDEMPOTENCY_KEYS: Map<(&Addr, &str), Timestamp> = Map::new("sender_idempotency_keys");
+
+/// Index of [`IDEMPOTENCY_KEYS`] by expiration time (in seconds), used to prune expired keys
+pub const IDEMPOTENCY_KEY_EXPIRATIONS: Map<(u64, &Addr, &str), ()> =
+    Map::new("sender_idempotency_key_expirations");
+
+#[cw_serde]
+pub struct GmpConfig {
+    /// Address on Neutron which executes messages coming from Axelar GMP
+    pub sender: Addr,
+    /// Neutron side of the IBC channel to Axelar, used for outgoing transfers
+    pub channel: String,
+    /// Axelar GMP account on Axelar chain, which routes outgoing transfers to EVM chains
+    pub gateway: String,
+    /// Axelar gas service account, which receives gas payments for execution on EVM chains
+    pub gas_service: String,
+    /// Bridged denoms which arrive over [`GmpConfig::channel`], only these can be sent back
+    /// to EVM chains
+    pub bridged_denoms: Vec<String>,
+}
+
+pub const GMP_CONFIG: Item<GmpConfig> = Item::new("gmp_config");
+
+#[cw_serde]
+pub struct OutboundTransfer {
+    /// Account which burned canonical funds, they are minted back to it if the transfer fails
+    pub sender: Addr,
+    pub canonical_denom: String,
+    pub bridged_denom: String,
+    pub amount: Uint128,
+    pub destination_chain: String,
+    pub evm_receiver: String,
+}
+
+/// Transfer of [`crate::msg::ExecuteMsg::BurnToEvm`], between IBC transfer submessage and its reply
+pub const PENDING_TRANSFER: Item<OutboundTransfer> = Item::new("pending_transfer");
+
+/// IBC transfers to EVM chains waiting for acknowledgement, keyed by source channel and sequence
+pub const OUTBOUND_TRANSFERS: Map<(&str, u64), OutboundTransfer> = Map::new("outbound_transfers");
+
+#[cw_serde]
+pub struct AttestationConfig {
+    /// Asset whose supply is attested
+    pub canonical_subdenom: String,
+    /// Axelar name of the EVM chain attestations are sent to
+    pub destination_chain: String,
+    /// EVM contract receiving attestations
+    pub destination_address: String,
+    /// Minimum number of seconds between two attestations
+    pub min_interval: u64,
+}
+
+pub const ATTESTATION_CONFIG: Item<AttestationConfig> = Item::new("attestation_config");
+
+/// Moment the latest supply attestation has been sent at
+pub const LAST_ATTESTATION: Item<Timestamp> = Item::new("last_attestation");
+
+#[cw_serde]
+pub struct PendingClaim {
+    /// Axelar name of the EVM chain the funds came from
+    pub source_chain: String,
+    pub canonical_denom: String,
+    /// Amount of canonical funds minted to contract itself and waiting to be claimed
+    pub amount: Uint128,
+}
+
+/// Funds which came over Axelar GMP to an invalid receiver,
+/// keyed by lowercase EVM address of their sender and bridged denom
+pub const PENDING_CLAIMS: Map<(&str, &str), PendingClaim> = Map::new("pending_claims");
+
+/// Nonce which the next claim signed by an EVM account must have, keyed by lowercase
+/// EVM address. It is bumped whenever a pending claim of the account is taken.
+pub const CLAIM_NONCES: Map<&str, u64> = Map::new("claim_nonces");
+
+/// EVM contract which is allowed to push exchange rate updates over Axelar GMP
+#[cw_serde]
+pub struct ExchangeRateSource {
+    /// Axelar name of the EVM chain
+    pub source_chain: String,
+    /// Lowercase address of the EVM contract
+    pub source_address: String,
+}
+
+pub const EXCHANGE_RATE_SOURCE: Item<ExchangeRateSource> = Item::new("exchange_rate_source");
+
+#[cw_serde]
+pub struct ExchangeRate {
+    /// Amount of stETH one wstETH is worth
+    pub rate: Decimal256,
+    /// Ethereum block the rate has been read at
+    pub block_number: u64,
+    /// Timestamp of the Ethereum block, in seconds
+    pub block_timestamp: u64,
+    /// Moment the rate has been received by this contract
+    pub updated_at: Timestamp,
+}
+
+pub const EXCHANGE_RATE: Item<ExchangeRate> = Item::new("exchange_rate");
+
+#[cw_serde]
+pub struct EscrowQuery {
+    /// IBC connection to the chain which holds the escrow account
+    pub connection_id: String,
+    /// Account on the counterparty chain which escrows funds bridged to Neutron
+    pub escrow_address: String,
+    /// Denom of escrowed funds on the counterparty chain
+    pub remote_denom: String,
+    /// How often, in blocks, the interchain query is updated
+    pub update_period: u64,
+    /// Is not set until registration of the interchain query is confirmed
+    pub query_id: Option<u64>,
+}
+
+/// Interchain queries of escrow balances backing bridged denoms, keyed by bridged denom
+pub const ESCROW_QUERIES: Map<&str, EscrowQuery> = Map::new("escrow_queries");
+
+/// Index of [`ESCROW_QUERIES`] by interchain query id
+pub const ESCROW_QUERY_IDS: Map<u64, String> = Map::new("escrow_query_ids");
+
+/// Bridged denom which escrow query is being registered, between submessage and its reply
+pub const PENDING_ESCROW_QUERY: Item<String> = Item::new("pending_escrow_query");
+
+#[cw_serde]
+pub struct EscrowBalance {
+    pub amount: Uint128,
+    /// Height of the counterparty chain the balance has been read at
+    pub remote_height: u64,
+    /// Height of Neutron the balance has been delivered at
+    pub local_height: u64,
+}
+
+/// Latest escrow balances delivered by interchain queries, keyed by bridged denom
+pub const ESCROW_BALANCES: Map<&str, EscrowBalance> = Map::new("escrow_balances");
+
+#[cw_serde]
+pub struct HealthCheck {
+    pub height: u64,
+    pub time: Timestamp,
+    /// Canonical subdenoms of assets whose supply exceeds bridged reserves
+    pub undercollateralized_assets: Vec<String>,
+    pub pruned_idempotency_keys: u64,
+}
+
+/// Result of the latest [`crate::msg::SudoMsg::HealthCheck`]
+pub const LAST_HEALTH_CHECK: Item<HealthCheck> = Item::new("last_health_check");
+
+#[cw_serde]
+pub struct WithdrawalQueueConfig {
+    /// Burns of larger amounts of canonical funds are not paid out instantly
+    pub threshold: Uint128,
+    /// Number of seconds a queued withdrawal waits before it can be claimed
+    pub delay: u64,
+}
+
+/// Withdrawal queues of assets, keyed by canonical subdenom
+pub const WITHDRAWAL_QUEUES: Map<&str, WithdrawalQueueConfig> = Map::new("withdrawal_queues");
+
+#[cw_serde]
+pub struct Withdrawal {
+    /// Account which burned canonical funds, they are minted back to it if the withdrawal
+    /// is cancelled
+    pub sender: Addr,
+    pub receiver: Addr,
+    pub canonical_denom: String,
+    /// Amount of burned canonical funds
+    pub amount: Uint128,
+    /// Bridged funds which are going to be paid out
+    pub payout: Vec<Coin>,
+    /// Moment the withdrawal can be claimed at
+    pub release_at: Timestamp,
+    /// Set if the payout is sent to an EVM chain over Axelar instead of `receiver`
+    pub evm_transfer: Option<EvmTransfer>,
+}
+
+#[cw_serde]
+pub struct EvmTransfer {
+    pub destination_chain: String,
+    pub evm_receiver: String,
+    /// Part of the payout which pays for execution on the destination chain
+    pub gas_fee: Uint128,
+}
+
+/// Funds the contract is expected to hold on behalf of users, keyed by denom: bridged funds
+/// backing canonical supply or queued withdrawals, and canonical funds of pending claims and
+/// intent deposits. Anything the contract holds above that is surplus.
+pub const EXPECTED_BALANCES: Map<&str, Uint128> = Map::new("expected_balances");
+
+/// Payouts of large burns waiting to be released, keyed by sequential id
+pub const WITHDRAWALS: Map<u64, Withdrawal> = Map::new("withdrawals");
+
+/// Index of [`WITHDRAWALS`] by receiver
+pub const RECEIVER_WITHDRAWALS: Map<(&Addr, u64), ()> = Map::new("receiver_withdrawals");
+
+/// Id of the next queued withdrawal
+pub const NEXT_WITHDRAWAL_ID: Item<u64> = Item::new("next_withdrawal_id");
+
+/// Bridged funds owed to queued withdrawals, keyed by bridged denom. They are still held
+/// by the contract, but can not pay out other burns.
+pub const QUEUED_PAYOUTS: Map<&str, Uint128> = Map::new("queued_payouts");
+
+#[cw_serde]
+#[derive(Default)]
+pub struct ReferralStats {
+    pub minted: Uint128,
+    pub burned: Uint128,
+    pub mints: u64,
+    pub burns: u64,
+}
+
+/// Totals of mints and burns attributed to referral codes, keyed by referral code
+/// and canonical denom
+pub const REFERRAL_STATS: Map<(&str, &str), ReferralStats> = Map::new("referral_stats");
+
+/// Once set, this instance has handed its canonical denoms and reserves over to the successor
+/// contract, and both mint and burn are permanently disabled.
+pub const SUCCESSOR: Item<Addr> = Item::new("successor");
+
+#[cw_serde]
+pub struct AdminAction {
+    /// Account which performed the action
+    pub actor: Addr,
+    pub height: u64,
+    pub time: Timestamp,
+    /// Name of the handler, e.g. `set_bridge_status`
+    pub action: String,
+    /// JSON encoded value affected by the action before it, if there was any
+    pub old_value: Option<String>,
+    /// JSON encoded value affected by the action after it, if there is any
+    pub new_value: Option<String>,
+}
+
+/// Append-only log of privileged actions, keyed by sequential id
+pub const ADMIN_LOG: Map<u64, AdminAction> = Map::new("admin_log");
+
+/// Number of entries in [`ADMIN_LOG`], which is also the id of the next one
+pub const ADMIN_LOG_LENGTH: Item<u64> = Item::new("admin_log_length");
@@ -0,0 +1,166 @@
+use crate::{
+    execute::{increase_expected_balance, prune_idempotency_keys},
+    state::{
+        EscrowBalance, HealthCheck, OutboundTransfer, ASSETS, ESCROW_BALANCES, ESCROW_QUERIES,
+        ESCROW_QUERY_IDS, LAST_HEALTH_CHECK, OUTBOUND_TRANSFERS, SUCCESSOR,
+    },
+    ContractError, ContractResult,
+};
+use cosmwasm_std::{
+    attr, CosmosMsg, Deps, DepsMut, Env, Order, Response, StdResult, Storage, Uint128,
+};
+use neutron_sdk::{
+    bindings::{msg::NeutronMsg, query::NeutronQuery},
+    interchain_queries::{get_registered_query, query_kv_result, v045::types::Balances},
+    sudo::msg::RequestPacket,
+};
+
+/// How many expired idempotency keys are pruned by every health check
+const HEALTH_CHECK_PRUNE_LIMIT: usize = 100;
+
+/// Checks reserves and prunes idempotency keys. There are no rate-limit windows to roll over,
+/// amount bounds and the withdrawal queue don't accumulate anything over time.
+pub(crate) fn sudo_health_check(
+    deps: DepsMut<NeutronQuery>,
+    env: Env,
+) -> ContractResult<Response<NeutronMsg>> {
+    let pruned_idempotency_keys =
+        prune_idempotency_keys(deps.storage, env.block.time, HEALTH_CHECK_PRUNE_LIMIT)?;
+
+    // reserves and denom admin belong to the successor after migration, nothing to check here
+    let undercollateralized_assets = if SUCCESSOR.may_load(deps.storage)?.is_some() {
+        vec![]
+    } else {
+        undercollateralized_assets(deps.as_ref(), &env)?
+    };
+
+    let health_check = HealthCheck {
+        height: env.block.height,
+        time: env.block.time,
+        undercollateralized_assets,
+        pruned_idempotency_keys,
+    };
+    LAST_HEALTH_CHECK.save(deps.storage, &health_check)?;
+
+    let healthy = health_check.undercollateralized_assets.is_empty();
+    let mut response = Response::new().add_attributes([
+        attr("action", "health_check"),
+        attr("healthy", healthy.to_string()),
+    ]);
+    // empty attribute values are rejected by the chain
+    if !healthy {
+        response = response.add_attribute(
+            "undercollateralized_assets",
+            health_check.undercollateralized_assets.join(","),
+        );
+    }
+    Ok(response.add_attribute(
+        "pruned_idempotency_keys",
+        pruned_idempotency_keys.to_string(),
+    ))
+}
+
+pub(crate) fn sudo_kv_query_result(
+    deps: DepsMut<NeutronQuery>,
+    query_id: u64,
+) -> ContractResult<Response<NeutronMsg>> {
+    let bridged_denom = ESCROW_QUERY_IDS
+        .may_load(deps.storage, query_id)?
+        .ok_or(ContractError::UnknownInterchainQuery { query_id })?;
+    let escrow_query = ESCROW_QUERIES.load(deps.storage, &bridged_denom)?;
+
+    let registered_query = get_registered_query(deps.as_ref(), query_id)?.registered_query;
+    let balances: Balances = query_kv_result(deps.as_ref(), query_id)?;
+    let escrow_balance = EscrowBalance {
+        amount: balances
+            .coins
+            .into_iter()
+            .find(|coin| coin.denom == escrow_query.remote_denom)
+            .map(|coin| coin.amount)
+            .unwrap_or_default(),
+        remote_height: registered_query
+            .last_submitted_result_remote_height
+            .revision_height,
+        local_height: registered_query.last_submitted_result_local_height,
+    };
+    ESCROW_BALANCES.save(deps.storage, &bridged_denom, &escrow_balance)?;
+
+    Ok(Response::new().add_attributes([
+        attr("action", "escrow_balance"),
+        attr("bridged_denom", bridged_denom),
+        attr("amount", escrow_balance.amount),
+        attr("remote_height", escrow_balance.remote_height.to_string()),
+    ]))
+}
+
+pub(crate) fn sudo_transfer_acknowledged(
+    deps: DepsMut<NeutronQuery>,
+    request: RequestPacket,
+) -> ContractResult<Response<NeutronMsg>> {
+    let Some(transfer) = take_outbound_transfer(deps.storage, &request)? else {
+        return Ok(Response::new());
+    };
+    Ok(Response::new().add_attributes([
+        attr("action", "transfer_acknowledged"),
+        attr("amount", transfer.amount),
+        attr("destination_chain", transfer.destination_chain),
+        attr("evm_receiver", transfer.evm_receiver),
+    ]))
+}
+
+pub(crate) fn sudo_transfer_failed(
+    deps: DepsMut<NeutronQuery>,
+    request: RequestPacket,
+    reason: &str,
+) -> ContractResult<Response<NeutronMsg>> {
+    let Some(transfer) = take_outbound_transfer(deps.storage, &request)? else {
+        return Ok(Response::new());
+    };
+    // bridged funds are refunded to this contract by IBC, so canonical funds are backed again
+    increase_expected_balance(deps.storage, &transfer.bridged_denom, transfer.amount)?;
+    let mint_msg: CosmosMsg<NeutronMsg> =
+        NeutronMsg::submit_mint_tokens(transfer.canonical_denom, transfer.amount, &transfer.sender)
+            .into();
+    Ok(Response::new().add_message(mint_msg).add_attributes([
+        attr("action", "transfer_failed"),
+        attr("amount", transfer.amount),
+        attr("sender", transfer.sender),
+        attr("reason", reason),
+    ]))
+}
+
+/// Removes the transfer which `request` belongs to, transfers not made by
+/// [`crate::msg::ExecuteMsg::BurnToEvm`] are not tracked
+fn take_outbound_transfer(
+    storage: &mut dyn Storage,
+    request: &RequestPacket,
+) -> ContractResult<Option<OutboundTransfer>> {
+    let (Some(channel), Some(sequence)) = (&request.source_channel, request.sequence) else {
+        return Ok(None);
+    };
+    let transfer = OUTBOUND_TRANSFERS.may_load(storage, (channel, sequence))?;
+    OUTBOUND_TRANSFERS.remove(storage, (channel, sequence));
+    Ok(transfer)
+}
+
+/// Returns canonical subdenoms of assets whose supply is not fully backed by bridged reserves
+fn undercollateralized_assets(deps: Deps<NeutronQuery>, env: &Env) -> ContractResult<Vec<String>> {
+    let assets = ASSETS
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    let mut undercollateralized = vec![];
+    for (subdenom, asset) in assets {
+        let supply = deps.querier.query_supply(&asset.canonical_denom)?.amount;
+        let mut reserves = Uint128::zero();
+        for bridged_denom in &asset.bridged_denoms {
+            let balance = deps
+                .querier
+                .query_balance(&env.contract.address, bridged_denom)?;
+            reserves = reserves.checked_add(balance.amount)?;
+        }
+        if reserves < supply {
+            undercollateralized.push(subdenom);
+        }
+    }
+    Ok(undercollateralized)
+}
@@ -0,0 +1,269 @@
+//! Mocks for unit tests of this contract and of contracts integrating with it, available with
+//! `testing` feature enabled.
+
+use crate::{
+    contract::{instantiate, reply, CREATE_DENOM_REPLY_ID},
+    msg::InstantiateMsg,
+    ContractResult,
+};
+use cosmwasm_std::{
+    from_json,
+    testing::{MockApi, MockQuerier, MockStorage},
+    to_json_binary, Addr, AllBalanceResponse, BankMsg, BankQuery, Binary, Coin,
+    ContractResult as QuerierContractResult, CosmosMsg, DepsMut, Empty, Env, MessageInfo,
+    OwnedDeps, Querier, QuerierResult, QueryRequest, Reply, Response, SubMsgResponse, SubMsgResult,
+    SystemError, SystemResult, Uint128,
+};
+use neutron_sdk::{
+    bindings::{msg::IbcFee, msg::NeutronMsg, query::NeutronQuery},
+    query::{
+        min_ibc_fee::MinIbcFeeResponse,
+        token_factory::{DenomAdminResponse, FullDenomResponse},
+    },
+};
+use std::{collections::HashMap, marker::PhantomData};
+
+/// Mock querier which, on top of [`MockQuerier`], answers tokenfactory and minimal IBC fee
+/// queries of Neutron. Bank balances, and hence supply, can be changed with
+/// [`NeutronMockQuerier::apply_messages`] as tokenfactory messages are executed.
+pub struct NeutronMockQuerier {
+    pub base: MockQuerier<NeutronQuery>,
+    /// Admins of tokenfactory denoms, keyed by full denom
+    pub denom_admins: HashMap<String, Addr>,
+    /// Returned by `NeutronQuery::MinIbcFee`
+    pub min_ibc_fee: IbcFee,
+}
+
+impl Default for NeutronMockQuerier {
+    fn default() -> Self {
+        Self {
+            base: MockQuerier::new(&[]),
+            denom_admins: HashMap::new(),
+            min_ibc_fee: IbcFee {
+                recv_fee: vec![],
+                ack_fee: vec![],
+                timeout_fee: vec![],
+            },
+        }
+    }
+}
+
+impl Querier for NeutronMockQuerier {
+    fn raw_query(&self, bin_request: &[u8]) -> QuerierResult {
+        let request: QueryRequest<NeutronQuery> = match from_json(bin_request) {
+            Ok(request) => request,
+            Err(err) => {
+                return SystemResult::Err(SystemError::InvalidRequest {
+                    error: format!("Parsing query request: {err}"),
+                    request: bin_request.into(),
+                })
+            }
+        };
+        match request {
+            QueryRequest::Custom(query) => self.handle_neutron_query(query),
+            request => self.base.handle_query(&request),
+        }
+    }
+}
+
+impl NeutronMockQuerier {
+    /// Full tokenfactory denom of `subdenom` created by `creator`
+    pub fn full_denom(creator: &Addr, subdenom: &str) -> String {
+        format!("factory/{creator}/{subdenom}")
+    }
+
+    /// Creates tokenfactory denom `subdenom` with `creator` as its admin, returning its full denom
+    pub fn create_denom(&mut self, creator: &Addr, subdenom: &str) -> String {
+        let denom = Self::full_denom(creator, subdenom);
+        self.denom_admins.insert(denom.clone(), creator.clone());
+        denom
+    }
+
+    pub fn balance(&self, address: &str, denom: &str) -> Uint128 {
+        self.balances(address)
+            .into_iter()
+            .find(|coin| coin.denom == denom)
+            .map_or(Uint128::zero(), |coin| coin.amount)
+    }
+
+    pub fn balances(&self, address: &str) -> Vec<Coin> {
+        let request = QueryRequest::Bank(BankQuery::AllBalances {
+            address: address.to_string(),
+        });
+        let response = self.base.handle_query(&request).unwrap().unwrap();
+        from_json::<AllBalanceResponse>(response).unwrap().amount
+    }
+
+    pub fn add_balance(&mut self, address: &str, denom: &str, amount: Uint128) {
+        let mut balances = self.balances(address);
+        match balances.iter_mut().find(|coin| coin.denom == denom) {
+            Some(coin) => coin.amount += amount,
+            None => balances.push(Coin::new(amount.u128(), denom)),
+        }
+        self.base.update_balance(address, balances);
+    }
+
+    /// Panics if `address` holds less than `amount` of `denom`
+    pub fn remove_balance(&mut self, address: &str, denom: &str, amount: Uint128) {
+        let mut balances = self.balances(address);
+        let coin = balances
+            .iter_mut()
+            .find(|coin| coin.denom == denom)
+            .unwrap_or_else(|| panic!("{address} holds no {denom}"));
+        coin.amount -= amount;
+        balances.retain(|coin| !coin.amount.is_zero());
+        self.base.update_balance(address, balances);
+    }
+
+    /// Executes tokenfactory messages and bank sends of `response` sent by `sender`, updating
+    /// denom admins and balances. Other messages are ignored.
+    pub fn apply_messages(&mut self, sender: &Addr, response: &Response<NeutronMsg>) {
+        for submsg in &response.messages {
+            match &submsg.msg {
+                CosmosMsg::Custom(msg) => self.apply_neutron_msg(sender, msg.clone()),
+                CosmosMsg::Bank(BankMsg::Send { to_address, amount }) => {
+                    for coin in amount {
+                        self.remove_balance(sender.as_str(), &coin.denom, coin.amount);
+                        self.add_balance(to_address, &coin.denom, coin.amount);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn apply_neutron_msg(&mut self, sender: &Addr, msg: NeutronMsg) {
+        match msg {
+            NeutronMsg::CreateDenom { subdenom } => {
+                self.create_denom(sender, &subdenom);
+            }
+            NeutronMsg::ChangeAdmin {
+                denom,
+                new_admin_address,
+            } => {
+                self.ensure_admin(sender, &denom);
+                self.denom_admins
+                    .insert(denom, Addr::unchecked(new_admin_address));
+            }
+            NeutronMsg::MintTokens {
+                denom,
+                amount,
+                mint_to_address,
+            } => {
+                self.ensure_admin(sender, &denom);
+                self.add_balance(&mint_to_address, &denom, amount);
+            }
+            NeutronMsg::BurnTokens { denom, amount, .. } => {
+                self.ensure_admin(sender, &denom);
+                self.remove_balance(sender.as_str(), &denom, amount);
+            }
+            _ => {}
+        }
+    }
+
+    fn ensure_admin(&self, sender: &Addr, denom: &str) {
+        assert_eq!(
+            self.denom_admins.get(denom),
+            Some(sender),
+            "{sender} is not an admin of {denom}"
+        );
+    }
+
+    fn handle_neutron_query(&self, query: NeutronQuery) -> QuerierResult {
+        let response: Binary = match query {
+            NeutronQuery::FullDenom {
+                creator_addr,
+                subdenom,
+            } => to_json_binary(&FullDenomResponse {
+                denom: Self::full_denom(&Addr::unchecked(creator_addr), &subdenom),
+            }),
+            // despite its name, the query expects a full denom
+            NeutronQuery::DenomAdmin { subdenom } => match self.denom_admins.get(&subdenom) {
+                Some(admin) => to_json_binary(&DenomAdminResponse {
+                    admin: admin.to_string(),
+                }),
+                None => {
+                    return SystemResult::Ok(QuerierContractResult::Err(format!(
+                        "denom {subdenom} does not exist"
+                    )))
+                }
+            },
+            NeutronQuery::MinIbcFee {} => to_json_binary(&MinIbcFeeResponse {
+                min_fee: self.min_ibc_fee.clone(),
+            }),
+            query => {
+                return SystemResult::Err(SystemError::UnsupportedRequest {
+                    kind: format!("{query:?}"),
+                })
+            }
+        }
+        .unwrap();
+        SystemResult::Ok(QuerierContractResult::Ok(response))
+    }
+}
+
+pub type NeutronMockDeps = OwnedDeps<MockStorage, MockApi, NeutronMockQuerier, NeutronQuery>;
+
+pub fn mock_neutron_dependencies() -> NeutronMockDeps {
+    OwnedDeps {
+        storage: MockStorage::default(),
+        api: MockApi::default(),
+        querier: NeutronMockQuerier::default(),
+        custom_query_type: PhantomData,
+    }
+}
+
+/// Instantiates the contract and runs the reply creating its canonical denom, like the chain
+/// would. Returns the instantiate response and the canonical denom.
+pub fn instantiate_with_reply(
+    deps: &mut NeutronMockDeps,
+    env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> ContractResult<(Response<NeutronMsg>, String)> {
+    let response = instantiate(deps.as_mut(), env.clone(), info, msg)?;
+    let replies = process_create_denom(deps, env, &response)?;
+    let canonical_denom = replies
+        .first()
+        .and_then(|reply| {
+            reply
+                .attributes
+                .iter()
+                .find(|attr| attr.key == "canonical_denom")
+        })
+        .map(|attr| attr.value.clone())
+        .expect("instantiate creates a canonical denom");
+    Ok((response, canonical_denom))
+}
+
+/// Creates tokenfactory denoms requested by submessages of `response` and runs replies of
+/// the contract to them, e.g. after `ExecuteMsg::RegisterAsset`. Returns responses of replies.
+pub fn process_create_denom(
+    deps: &mut NeutronMockDeps,
+    env: Env,
+    response: &Response<NeutronMsg>,
+) -> ContractResult<Vec<Response<Empty>>> {
+    let mut replies = vec![];
+    for submsg in &response.messages {
+        let CosmosMsg::Custom(NeutronMsg::CreateDenom { subdenom }) = &submsg.msg else {
+            continue;
+        };
+        deps.querier.create_denom(&env.contract.address, subdenom);
+        replies.push(reply_ok(deps.as_mut(), env.clone(), CREATE_DENOM_REPLY_ID)?);
+    }
+    Ok(replies)
+}
+
+fn reply_ok(deps: DepsMut<NeutronQuery>, env: Env, id: u64) -> ContractResult<Response<Empty>> {
+    reply(
+        deps,
+        env,
+        Reply {
+            id,
+            result: SubMsgResult::Ok(SubMsgResponse {
+                events: vec![],
+                data: None,
+            }),
+        },
+    )
+}
@@ -0,0 +1,94 @@
+use crate::{
+    contract::execute,
+    msg::ExecuteMsg,
+    tests::helpers::{assert_asset, instantiate_wrapper, VALID_IBC_DENOM, VALID_IBC_DENOM_2},
+    ContractError,
+};
+use cosmwasm_std::{attr, testing::mock_info};
+
+#[test]
+fn stranger() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    let err = execute(
+        deps.as_mut(),
+        env,
+        mock_info("stranger", &[]),
+        ExecuteMsg::AddBridgedDenom {
+            canonical_subdenom: "eth".to_string(),
+            bridged_denom: VALID_IBC_DENOM_2.to_string(),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::NotBridgeManager {});
+}
+
+#[test]
+fn unknown_asset() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    let err = execute(
+        deps.as_mut(),
+        env,
+        mock_info("admin", &[]),
+        ExecuteMsg::AddBridgedDenom {
+            canonical_subdenom: "steth".to_string(),
+            bridged_denom: VALID_IBC_DENOM_2.to_string(),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::AssetNotRegistered {
+            subdenom: "steth".to_string()
+        }
+    );
+}
+
+#[test]
+fn already_registered() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    let err = execute(
+        deps.as_mut(),
+        env,
+        mock_info("admin", &[]),
+        ExecuteMsg::AddBridgedDenom {
+            canonical_subdenom: "eth".to_string(),
+            bridged_denom: VALID_IBC_DENOM.to_string(),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::BridgedDenomAlreadyRegistered {
+            denom: VALID_IBC_DENOM.to_string()
+        }
+    );
+}
+
+#[test]
+fn success() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    let response = execute(
+        deps.as_mut(),
+        env,
+        mock_info("admin", &[]),
+        ExecuteMsg::AddBridgedDenom {
+            canonical_subdenom: "eth".to_string(),
+            bridged_denom: VALID_IBC_DENOM_2.to_string(),
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "add_bridged_denom"),
+            attr("canonical_subdenom", "eth"),
+            attr("bridged_denom", VALID_IBC_DENOM_2),
+        ]
+    );
+    assert_asset(
+        deps.as_ref(),
+        "eth",
+        &[VALID_IBC_DENOM, VALID_IBC_DENOM_2],
+        "eth",
+    );
+}
@@ -0,0 +1,140 @@
+use crate::{
+    contract::execute,
+    execute::FEE_DENOM,
+    gmp::abi_encode_uint256s,
+    msg::ExecuteMsg,
+    state::LAST_ATTESTATION,
+    tests::helpers::{instantiate_wrapper, set_gmp_config, MockDeps, VALID_IBC_DENOM},
+    ContractError,
+};
+use cosmwasm_std::{attr, coin, testing::mock_info, Coin, Env, Uint256};
+
+const ATTESTATION_RECEIVER: &str = "0x00000000000000000000000000000000000000cc";
+
+fn setup() -> (MockDeps, Env) {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    set_gmp_config(deps.as_mut(), env.clone(), "admin").unwrap();
+    let response = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("admin", &[]),
+        ExecuteMsg::SetAttestationConfig {
+            canonical_subdenom: "eth".to_string(),
+            destination_chain: "ethereum".to_string(),
+            destination_address: ATTESTATION_RECEIVER.to_string(),
+            min_interval: 3600,
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "set_attestation_config"),
+            attr("canonical_subdenom", "eth"),
+            attr("destination_chain", "ethereum"),
+            attr("destination_address", ATTESTATION_RECEIVER),
+            attr("min_interval", "3600"),
+        ]
+    );
+    (deps, env)
+}
+
+fn attest(deps: &mut MockDeps, env: &Env, funds: &[Coin]) -> ContractError {
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("stranger", funds),
+        ExecuteMsg::AttestSupply {},
+    )
+    .unwrap_err()
+}
+
+#[test]
+fn not_configured() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    let err = attest(&mut deps, &env, &[coin(100, FEE_DENOM)]);
+    assert_eq!(err, ContractError::GmpNotConfigured {});
+}
+
+#[test]
+fn invalid_gas_payment() {
+    let (mut deps, env) = setup();
+    let err = attest(&mut deps, &env, &[]);
+    assert_eq!(err, ContractError::InvalidGasPayment {});
+    let err = attest(&mut deps, &env, &[coin(0, FEE_DENOM)]);
+    assert_eq!(err, ContractError::InvalidGasPayment {});
+    let err = attest(&mut deps, &env, &[coin(100, "uaxl")]);
+    assert_eq!(
+        err,
+        ContractError::UnexpectedFunds {
+            denom: "uaxl".to_string(),
+        }
+    );
+}
+
+#[test]
+fn too_frequent() {
+    let (mut deps, env) = setup();
+    let last_attestation = env.block.time.minus_seconds(600);
+    LAST_ATTESTATION
+        .save(deps.as_mut().storage, &last_attestation)
+        .unwrap();
+    let err = attest(&mut deps, &env, &[coin(100, FEE_DENOM)]);
+    assert_eq!(
+        err,
+        ContractError::AttestationTooFrequent {
+            next_at: last_attestation.plus_seconds(3600),
+        }
+    );
+}
+
+#[test]
+fn config_by_stranger() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    let err = execute(
+        deps.as_mut(),
+        env,
+        mock_info("stranger", &[]),
+        ExecuteMsg::SetAttestationConfig {
+            canonical_subdenom: "eth".to_string(),
+            destination_chain: "ethereum".to_string(),
+            destination_address: ATTESTATION_RECEIVER.to_string(),
+            min_interval: 3600,
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+}
+
+#[test]
+fn config_of_unregistered_asset() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    let err = execute(
+        deps.as_mut(),
+        env,
+        mock_info("admin", &[]),
+        ExecuteMsg::SetAttestationConfig {
+            canonical_subdenom: "steth".to_string(),
+            destination_chain: "ethereum".to_string(),
+            destination_address: ATTESTATION_RECEIVER.to_string(),
+            min_interval: 3600,
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::AssetNotRegistered {
+            subdenom: "steth".to_string(),
+        }
+    );
+}
+
+#[test]
+fn payload_encoding() {
+    let payload = abi_encode_uint256s(&[Uint256::from(1u8), Uint256::from(0x0102u16)]);
+    let mut expected = vec![0u8; 64];
+    expected[31] = 1;
+    expected[62] = 1;
+    expected[63] = 2;
+    assert_eq!(payload, expected);
+}
@@ -1,8 +1,11 @@
 use crate::{
     contract::execute,
     msg::ExecuteMsg,
-    state::CONFIG,
-    tests::helpers::{instantiate_wrapper, VALID_IBC_DENOM},
+    state::{RoutingPolicy, ASSETS},
+    tests::helpers::{
+        instantiate_wrapper, register_asset_wrapper, VALID_IBC_DENOM, VALID_IBC_DENOM_2,
+        VALID_IBC_DENOM_3,
+    },
     ContractError,
 };
 use cosmwasm_std::{attr, coin, testing::mock_info, BankMsg, Response, Uint128};
@@ -15,7 +18,12 @@ fn no_funds() {
         deps.as_mut(),
         env,
         mock_info("stranger", &[]),
-        ExecuteMsg::Burn { receiver: None },
+        ExecuteMsg::Burn {
+            receiver: None,
+            bridged_denom: None,
+            referral: None,
+            memo: None,
+        },
     )
     .unwrap_err();
     assert_eq!(err, ContractError::NothingToBurn {});
@@ -28,21 +36,39 @@ fn incorrect_funds() {
         deps.as_mut(),
         env,
         mock_info("stranger", &[coin(10, "ldo")]),
-        ExecuteMsg::Burn { receiver: None },
+        ExecuteMsg::Burn {
+            receiver: None,
+            bridged_denom: None,
+            referral: None,
+            memo: None,
+        },
     )
     .unwrap_err();
-    assert_eq!(err, ContractError::NothingToBurn {});
+    assert_eq!(
+        err,
+        ContractError::UnexpectedFunds {
+            denom: "ldo".to_string(),
+        }
+    );
 }
 
 #[test]
 fn correct_funds() {
     let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
-    let config = CONFIG.load(deps.as_mut().storage).unwrap();
+    let asset = ASSETS.load(deps.as_mut().storage, "eth").unwrap();
+    deps.querier
+        .base
+        .update_balance(&env.contract.address, vec![coin(100, VALID_IBC_DENOM)]);
     let response = execute(
         deps.as_mut(),
         env,
-        mock_info("stranger", &[coin(10, &config.canonical_denom)]),
-        ExecuteMsg::Burn { receiver: None },
+        mock_info("stranger", &[coin(10, &asset.canonical_denom)]),
+        ExecuteMsg::Burn {
+            receiver: None,
+            bridged_denom: None,
+            referral: None,
+            memo: None,
+        },
     )
     .unwrap();
 
@@ -51,7 +77,7 @@ fn correct_funds() {
         "stranger",
         "stranger",
         10,
-        config.canonical_denom,
+        asset.canonical_denom,
         VALID_IBC_DENOM,
     );
 }
@@ -59,30 +85,46 @@ fn correct_funds() {
 #[test]
 fn mixed_funds() {
     let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
-    let config = CONFIG.load(deps.as_mut().storage).unwrap();
+    let asset = ASSETS.load(deps.as_mut().storage, "eth").unwrap();
     let err = execute(
         deps.as_mut(),
         env,
         mock_info(
             "stranger",
-            &[coin(10, config.canonical_denom), coin(20, "ldo")],
+            &[coin(10, asset.canonical_denom), coin(20, "ldo")],
         ),
-        ExecuteMsg::Burn { receiver: None },
+        ExecuteMsg::Burn {
+            receiver: None,
+            bridged_denom: None,
+            referral: None,
+            memo: None,
+        },
     )
     .unwrap_err();
-    assert_eq!(err, ContractError::ExtraFunds {});
+    assert_eq!(
+        err,
+        ContractError::UnexpectedFunds {
+            denom: "ldo".to_string(),
+        }
+    );
 }
 
 #[test]
 fn with_custom_receiver() {
     let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
-    let config = CONFIG.load(deps.as_mut().storage).unwrap();
+    let asset = ASSETS.load(deps.as_mut().storage, "eth").unwrap();
+    deps.querier
+        .base
+        .update_balance(&env.contract.address, vec![coin(100, VALID_IBC_DENOM)]);
     let response = execute(
         deps.as_mut(),
         env,
-        mock_info("stranger", &[coin(12, &config.canonical_denom)]),
+        mock_info("stranger", &[coin(12, &asset.canonical_denom)]),
         ExecuteMsg::Burn {
             receiver: Some("benefitiary".to_string()),
+            bridged_denom: None,
+            referral: None,
+            memo: None,
         },
     )
     .unwrap();
@@ -92,11 +134,258 @@ fn with_custom_receiver() {
         "stranger",
         "benefitiary",
         12,
-        config.canonical_denom,
+        asset.canonical_denom,
         VALID_IBC_DENOM,
     );
 }
 
+#[test]
+fn routed_by_canonical_denom() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    register_asset_wrapper(
+        deps.as_mut(),
+        env.clone(),
+        "steth",
+        &[VALID_IBC_DENOM_2, VALID_IBC_DENOM_3],
+    );
+    deps.querier.base.update_balance(
+        &env.contract.address,
+        vec![coin(100, VALID_IBC_DENOM_2), coin(100, VALID_IBC_DENOM_3)],
+    );
+    let response = execute(
+        deps.as_mut(),
+        env,
+        mock_info("stranger", &[coin(15, "steth")]),
+        ExecuteMsg::Burn {
+            receiver: None,
+            bridged_denom: None,
+            referral: None,
+            memo: None,
+        },
+    )
+    .unwrap();
+
+    assert_burn_send_messages_and_attrs(
+        &response,
+        "stranger",
+        "stranger",
+        15,
+        "steth",
+        VALID_IBC_DENOM_2,
+    );
+}
+
+#[test]
+fn preferred_bridged_denom() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    register_asset_wrapper(
+        deps.as_mut(),
+        env.clone(),
+        "steth",
+        &[VALID_IBC_DENOM_2, VALID_IBC_DENOM_3],
+    );
+    deps.querier.base.update_balance(
+        &env.contract.address,
+        vec![coin(100, VALID_IBC_DENOM_2), coin(100, VALID_IBC_DENOM_3)],
+    );
+    let response = execute(
+        deps.as_mut(),
+        env,
+        mock_info("stranger", &[coin(15, "steth")]),
+        ExecuteMsg::Burn {
+            receiver: None,
+            bridged_denom: Some(VALID_IBC_DENOM_3.to_string()),
+            referral: None,
+            memo: None,
+        },
+    )
+    .unwrap();
+
+    assert_burn_send_messages_and_attrs(
+        &response,
+        "stranger",
+        "stranger",
+        15,
+        "steth",
+        VALID_IBC_DENOM_3,
+    );
+}
+
+#[test]
+fn preferred_bridged_denom_insufficient_reserve() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    register_asset_wrapper(
+        deps.as_mut(),
+        env.clone(),
+        "steth",
+        &[VALID_IBC_DENOM_2, VALID_IBC_DENOM_3],
+    );
+    deps.querier.base.update_balance(
+        &env.contract.address,
+        vec![coin(100, VALID_IBC_DENOM_2), coin(10, VALID_IBC_DENOM_3)],
+    );
+    let err = execute(
+        deps.as_mut(),
+        env,
+        mock_info("stranger", &[coin(15, "steth")]),
+        ExecuteMsg::Burn {
+            receiver: None,
+            bridged_denom: Some(VALID_IBC_DENOM_3.to_string()),
+            referral: None,
+            memo: None,
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InsufficientReserves {
+            denom: VALID_IBC_DENOM_3.to_string(),
+            available: Uint128::new(10),
+            requested: Uint128::new(15),
+        }
+    );
+}
+
+#[test]
+fn preferred_bridged_denom_of_another_asset() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    register_asset_wrapper(deps.as_mut(), env.clone(), "steth", &[VALID_IBC_DENOM_2]);
+    let err = execute(
+        deps.as_mut(),
+        env,
+        mock_info("stranger", &[coin(15, "steth")]),
+        ExecuteMsg::Burn {
+            receiver: None,
+            bridged_denom: Some(VALID_IBC_DENOM.to_string()),
+            referral: None,
+            memo: None,
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::BridgedDenomNotInAsset {
+            denom: VALID_IBC_DENOM.to_string(),
+            subdenom: "steth".to_string(),
+        }
+    );
+}
+
+#[test]
+fn split_by_priority() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    register_asset_wrapper(
+        deps.as_mut(),
+        env.clone(),
+        "steth",
+        &[VALID_IBC_DENOM_3, VALID_IBC_DENOM_2],
+    );
+    deps.querier.base.update_balance(
+        &env.contract.address,
+        vec![coin(100, VALID_IBC_DENOM_2), coin(10, VALID_IBC_DENOM_3)],
+    );
+    let response = execute(
+        deps.as_mut(),
+        env,
+        mock_info("stranger", &[coin(15, "steth")]),
+        ExecuteMsg::Burn {
+            receiver: None,
+            bridged_denom: None,
+            referral: None,
+            memo: None,
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        response.messages[1].msg,
+        BankMsg::Send {
+            to_address: "stranger".to_string(),
+            amount: vec![coin(10, VALID_IBC_DENOM_3), coin(5, VALID_IBC_DENOM_2)],
+        }
+        .into()
+    );
+}
+
+#[test]
+fn largest_reserve_first() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    register_asset_wrapper(
+        deps.as_mut(),
+        env.clone(),
+        "steth",
+        &[VALID_IBC_DENOM_3, VALID_IBC_DENOM_2],
+    );
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("admin", &[]),
+        ExecuteMsg::SetRoutingPolicy {
+            canonical_subdenom: "steth".to_string(),
+            routing_policy: RoutingPolicy::LargestReserve,
+        },
+    )
+    .unwrap();
+    deps.querier.base.update_balance(
+        &env.contract.address,
+        vec![coin(100, VALID_IBC_DENOM_2), coin(10, VALID_IBC_DENOM_3)],
+    );
+    let response = execute(
+        deps.as_mut(),
+        env,
+        mock_info("stranger", &[coin(15, "steth")]),
+        ExecuteMsg::Burn {
+            receiver: None,
+            bridged_denom: None,
+            referral: None,
+            memo: None,
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        response.messages[1].msg,
+        BankMsg::Send {
+            to_address: "stranger".to_string(),
+            amount: vec![coin(15, VALID_IBC_DENOM_2)],
+        }
+        .into()
+    );
+}
+
+#[test]
+fn insufficient_total_reserves() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    register_asset_wrapper(
+        deps.as_mut(),
+        env.clone(),
+        "steth",
+        &[VALID_IBC_DENOM_2, VALID_IBC_DENOM_3],
+    );
+    deps.querier.base.update_balance(
+        &env.contract.address,
+        vec![coin(5, VALID_IBC_DENOM_2), coin(6, VALID_IBC_DENOM_3)],
+    );
+    let err = execute(
+        deps.as_mut(),
+        env,
+        mock_info("stranger", &[coin(15, "steth")]),
+        ExecuteMsg::Burn {
+            receiver: None,
+            bridged_denom: None,
+            referral: None,
+            memo: None,
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InsufficientReserves {
+            denom: VALID_IBC_DENOM_3.to_string(),
+            available: Uint128::new(11),
+            requested: Uint128::new(15),
+        }
+    );
+}
+
 fn assert_burn_send_messages_and_attrs(
     response: &Response<NeutronMsg>,
     sender: &str,
@@ -0,0 +1,461 @@
+use crate::{
+    contract::{execute, reply, sudo, BURN_TO_EVM_REPLY_ID},
+    execute::FEE_DENOM,
+    msg::{ExecuteMsg, InstantiateMsg, SudoMsg},
+    state::{
+        EvmTransfer, OutboundTransfer, WithdrawalQueueConfig, OUTBOUND_TRANSFERS, PENDING_TRANSFER,
+        WITHDRAWALS,
+    },
+    testing::{instantiate_with_reply, mock_neutron_dependencies},
+    tests::helpers::{
+        instantiate_wrapper, register_asset_wrapper, set_gmp_config, MockDeps, VALID_IBC_DENOM,
+        VALID_IBC_DENOM_2, VALID_IBC_DENOM_3,
+    },
+    ContractError,
+};
+use cosmwasm_std::{
+    attr, coin,
+    testing::{mock_env, mock_info},
+    to_json_binary, Addr, Binary, CosmosMsg, Env, Reply, SubMsgResponse, SubMsgResult, Uint128,
+};
+use neutron_sdk::{
+    bindings::msg::{MsgIbcTransferResponse, NeutronMsg},
+    sudo::msg::RequestPacket,
+};
+
+const EVM_RECEIVER: &str = "0x00000000000000000000000000000000000000dd";
+
+fn setup() -> (MockDeps, Env) {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    set_gmp_config(deps.as_mut(), env.clone(), "admin").unwrap();
+    (deps, env)
+}
+
+fn burn_to_evm(deps: &mut MockDeps, env: &Env, denom: &str, gas_fee: u128) -> ContractError {
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("stranger", &[coin(100, denom)]),
+        ExecuteMsg::BurnToEvm {
+            evm_receiver: EVM_RECEIVER.to_string(),
+            destination_chain: "ethereum".to_string(),
+            gas_fee: Uint128::new(gas_fee),
+        },
+    )
+    .unwrap_err()
+}
+
+/// Simulates reply to IBC transfer submessage, which assigns sequence 5 to the transfer
+fn track_transfer(deps: &mut MockDeps, env: &Env) -> OutboundTransfer {
+    let transfer = OutboundTransfer {
+        sender: Addr::unchecked("stranger"),
+        canonical_denom: "eth".to_string(),
+        bridged_denom: VALID_IBC_DENOM.to_string(),
+        amount: Uint128::new(100),
+        destination_chain: "ethereum".to_string(),
+        evm_receiver: EVM_RECEIVER.to_string(),
+    };
+    PENDING_TRANSFER
+        .save(deps.as_mut().storage, &transfer)
+        .unwrap();
+    reply(
+        deps.as_mut(),
+        env.clone(),
+        Reply {
+            id: BURN_TO_EVM_REPLY_ID,
+            result: SubMsgResult::Ok(SubMsgResponse {
+                events: vec![],
+                data: Some(
+                    to_json_binary(&MsgIbcTransferResponse {
+                        sequence_id: 5,
+                        channel: "channel-2".to_string(),
+                    })
+                    .unwrap(),
+                ),
+            }),
+        },
+    )
+    .unwrap();
+    transfer
+}
+
+fn request(sequence: u64) -> RequestPacket {
+    RequestPacket {
+        sequence: Some(sequence),
+        source_port: Some("transfer".to_string()),
+        source_channel: Some("channel-2".to_string()),
+        destination_port: None,
+        destination_channel: None,
+        data: None,
+        timeout_height: None,
+        timeout_timestamp: None,
+    }
+}
+
+#[test]
+fn gmp_not_configured() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    let err = burn_to_evm(&mut deps, &env, "eth", 0);
+    assert_eq!(err, ContractError::GmpNotConfigured {});
+}
+
+#[test]
+fn invalid_evm_receiver() {
+    let (mut deps, env) = setup();
+    let err = execute(
+        deps.as_mut(),
+        env,
+        mock_info("stranger", &[coin(100, "eth")]),
+        ExecuteMsg::BurnToEvm {
+            evm_receiver: "benefitiary".to_string(),
+            destination_chain: "ethereum".to_string(),
+            gas_fee: Uint128::zero(),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidEvmAddress {
+            address: "benefitiary".to_string(),
+            reason: "expected length of 42 chars".to_string(),
+        }
+    );
+}
+
+#[test]
+fn gas_fee_exceeds_amount() {
+    let (mut deps, env) = setup();
+    let err = burn_to_evm(&mut deps, &env, "eth", 100);
+    assert_eq!(
+        err,
+        ContractError::GasFeeExceedsAmount {
+            fee: Uint128::new(100),
+            amount: Uint128::new(100),
+        }
+    );
+}
+
+#[test]
+fn no_gmp_bridged_denom() {
+    let (mut deps, env) = setup();
+    register_asset_wrapper(
+        deps.as_mut(),
+        env.clone(),
+        "steth",
+        &[VALID_IBC_DENOM_2, VALID_IBC_DENOM_3],
+    );
+    let err = burn_to_evm(&mut deps, &env, "steth", 1);
+    assert_eq!(
+        err,
+        ContractError::NoGmpBridgedDenom {
+            subdenom: "steth".to_string(),
+        }
+    );
+}
+
+#[test]
+fn pays_out_gmp_bridged_denom() {
+    let mut deps = mock_neutron_dependencies();
+    let env = mock_env();
+    let contract = env.contract.address.clone();
+    // bridged denom which did not come over Axelar is the first by priority
+    let (_response, canonical_denom) = instantiate_with_reply(
+        &mut deps,
+        env.clone(),
+        mock_info("admin", &[]),
+        InstantiateMsg {
+            bridged_denom: VALID_IBC_DENOM_2.to_string(),
+            canonical_subdenom: "wsteth".to_string(),
+            owner: None,
+        },
+    )
+    .unwrap();
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("admin", &[]),
+        ExecuteMsg::AddBridgedDenom {
+            canonical_subdenom: "wsteth".to_string(),
+            bridged_denom: VALID_IBC_DENOM.to_string(),
+        },
+    )
+    .unwrap();
+    set_gmp_config(deps.as_mut(), env.clone(), "admin").unwrap();
+    deps.querier
+        .add_balance(contract.as_str(), VALID_IBC_DENOM, Uint128::new(100));
+    deps.querier
+        .add_balance(contract.as_str(), VALID_IBC_DENOM_2, Uint128::new(100));
+    deps.querier
+        .add_balance("stranger", &canonical_denom, Uint128::new(100));
+
+    let response = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(
+            "stranger",
+            &[coin(100, &canonical_denom), coin(5, FEE_DENOM)],
+        ),
+        ExecuteMsg::BurnToEvm {
+            evm_receiver: EVM_RECEIVER.to_string(),
+            destination_chain: "ethereum".to_string(),
+            gas_fee: Uint128::zero(),
+        },
+    )
+    .unwrap();
+    assert_eq!(response.attributes[6], attr("fee", format!("5{FEE_DENOM}")));
+    let CosmosMsg::Custom(NeutronMsg::IbcTransfer {
+        source_channel,
+        token,
+        ..
+    }) = &response.messages[1].msg
+    else {
+        panic!("expected IBC transfer, got {:?}", response.messages[1].msg);
+    };
+    assert_eq!(source_channel, "channel-2");
+    assert_eq!(token, &coin(100, VALID_IBC_DENOM));
+    assert_eq!(
+        response.attributes[2],
+        attr("bridged_denom", VALID_IBC_DENOM)
+    );
+}
+
+#[test]
+fn large_burn_is_queued() {
+    let mut deps = mock_neutron_dependencies();
+    let mut env = mock_env();
+    let contract = env.contract.address.clone();
+    let (_response, canonical_denom) = instantiate_with_reply(
+        &mut deps,
+        env.clone(),
+        mock_info("admin", &[]),
+        InstantiateMsg {
+            bridged_denom: VALID_IBC_DENOM.to_string(),
+            canonical_subdenom: "wsteth".to_string(),
+            owner: None,
+        },
+    )
+    .unwrap();
+    set_gmp_config(deps.as_mut(), env.clone(), "admin").unwrap();
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("admin", &[]),
+        ExecuteMsg::SetWithdrawalQueue {
+            canonical_subdenom: "wsteth".to_string(),
+            config: Some(WithdrawalQueueConfig {
+                threshold: Uint128::new(50),
+                delay: 60 * 60,
+            }),
+        },
+    )
+    .unwrap();
+    deps.querier
+        .add_balance(contract.as_str(), VALID_IBC_DENOM, Uint128::new(100));
+    deps.querier
+        .add_balance("stranger", &canonical_denom, Uint128::new(100));
+
+    let response = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("stranger", &[coin(100, &canonical_denom)]),
+        ExecuteMsg::BurnToEvm {
+            evm_receiver: EVM_RECEIVER.to_string(),
+            destination_chain: "ethereum".to_string(),
+            gas_fee: Uint128::new(1),
+        },
+    )
+    .unwrap();
+    // canonical funds are burned, but nothing is transferred yet
+    assert_eq!(response.messages.len(), 1);
+    let release_at = env.block.time.plus_seconds(60 * 60);
+    assert_eq!(
+        response.attributes[6..],
+        [
+            attr("withdrawal_id", "0"),
+            attr("release_at", release_at.to_string()),
+        ]
+    );
+    assert_eq!(
+        WITHDRAWALS
+            .load(deps.as_ref().storage, 0)
+            .unwrap()
+            .evm_transfer,
+        Some(EvmTransfer {
+            destination_chain: "ethereum".to_string(),
+            evm_receiver: EVM_RECEIVER.to_string(),
+            gas_fee: Uint128::new(1),
+        })
+    );
+
+    env.block.time = release_at;
+    let response = execute(
+        deps.as_mut(),
+        env,
+        mock_info("anyone", &[]),
+        ExecuteMsg::ClaimWithdrawal { id: 0 },
+    )
+    .unwrap();
+    assert_eq!(response.messages[0].id, BURN_TO_EVM_REPLY_ID);
+    let CosmosMsg::Custom(NeutronMsg::IbcTransfer { token, memo, .. }) = &response.messages[0].msg
+    else {
+        panic!("expected IBC transfer, got {:?}", response.messages[0].msg);
+    };
+    assert_eq!(token, &coin(100, VALID_IBC_DENOM));
+    assert!(memo.contains(EVM_RECEIVER));
+    assert_eq!(
+        PENDING_TRANSFER.load(deps.as_ref().storage).unwrap(),
+        OutboundTransfer {
+            sender: Addr::unchecked("stranger"),
+            canonical_denom,
+            bridged_denom: VALID_IBC_DENOM.to_string(),
+            amount: Uint128::new(100),
+            destination_chain: "ethereum".to_string(),
+            evm_receiver: EVM_RECEIVER.to_string(),
+        }
+    );
+}
+
+#[test]
+fn gmp_bridged_denom_not_registered() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM_2, "eth");
+    let err = set_gmp_config(deps.as_mut(), env, "admin").unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::BridgedDenomNotRegistered {
+            denom: VALID_IBC_DENOM.to_string(),
+        }
+    );
+}
+
+#[test]
+fn multiple_gmp_bridged_denoms_of_asset() {
+    let (mut deps, env) = setup();
+    register_asset_wrapper(
+        deps.as_mut(),
+        env.clone(),
+        "steth",
+        &[VALID_IBC_DENOM_2, VALID_IBC_DENOM_3],
+    );
+    let err = execute(
+        deps.as_mut(),
+        env,
+        mock_info("admin", &[]),
+        ExecuteMsg::SetGmpConfig {
+            sender: "axelar_relayer".to_string(),
+            channel: "channel-2".to_string(),
+            gateway: "axelar_gateway".to_string(),
+            gas_service: "axelar_gas_service".to_string(),
+            bridged_denoms: vec![
+                VALID_IBC_DENOM.to_string(),
+                VALID_IBC_DENOM_2.to_string(),
+                VALID_IBC_DENOM_3.to_string(),
+            ],
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::MultipleGmpBridgedDenoms {
+            subdenom: "steth".to_string(),
+        }
+    );
+}
+
+#[test]
+fn transfer_acknowledged() {
+    let (mut deps, env) = setup();
+    track_transfer(&mut deps, &env);
+    let response = sudo(
+        deps.as_mut(),
+        env,
+        SudoMsg::Response {
+            request: request(5),
+            data: Binary::default(),
+        },
+    )
+    .unwrap();
+    assert!(response.messages.is_empty());
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "transfer_acknowledged"),
+            attr("amount", "100"),
+            attr("destination_chain", "ethereum"),
+            attr("evm_receiver", EVM_RECEIVER),
+        ]
+    );
+    assert!(OUTBOUND_TRANSFERS.is_empty(deps.as_ref().storage));
+}
+
+#[test]
+fn migration_waits_for_transfer() {
+    let (mut deps, env) = setup();
+    track_transfer(&mut deps, &env);
+    let migrate = ExecuteMsg::MigrateToSuccessor {
+        successor: "successor".to_string(),
+    };
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("admin", &[]),
+        migrate.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::TransfersInFlight {});
+
+    sudo(
+        deps.as_mut(),
+        env.clone(),
+        SudoMsg::Response {
+            request: request(5),
+            data: Binary::default(),
+        },
+    )
+    .unwrap();
+    execute(deps.as_mut(), env, mock_info("admin", &[]), migrate).unwrap();
+}
+
+#[test]
+fn transfer_timed_out() {
+    let (mut deps, env) = setup();
+    let transfer = track_transfer(&mut deps, &env);
+    let response = sudo(
+        deps.as_mut(),
+        env,
+        SudoMsg::Timeout {
+            request: request(5),
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        response.messages[0].msg,
+        NeutronMsg::submit_mint_tokens("eth", transfer.amount, &transfer.sender).into()
+    );
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "transfer_failed"),
+            attr("amount", "100"),
+            attr("sender", "stranger"),
+            attr("reason", "timeout"),
+        ]
+    );
+    assert!(OUTBOUND_TRANSFERS.is_empty(deps.as_ref().storage));
+}
+
+#[test]
+fn untracked_transfer_failed() {
+    let (mut deps, env) = setup();
+    track_transfer(&mut deps, &env);
+    let response = sudo(
+        deps.as_mut(),
+        env,
+        SudoMsg::Error {
+            request: request(6),
+            details: "ack error".to_string(),
+        },
+    )
+    .unwrap();
+    assert!(response.messages.is_empty());
+    assert!(OUTBOUND_TRANSFERS.has(deps.as_ref().storage, ("channel-2", 5)));
+}
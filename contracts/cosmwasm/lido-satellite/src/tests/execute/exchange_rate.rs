@@ -0,0 +1,202 @@
+use crate::{
+    contract::execute,
+    msg::{ExecuteMsg, MAX_EXCHANGE_RATE_AGE, MAX_EXCHANGE_RATE_CLOCK_SKEW},
+    state::EXCHANGE_RATE,
+    tests::helpers::{instantiate_wrapper, set_gmp_config, MockDeps, VALID_IBC_DENOM},
+    ContractError, ContractResult,
+};
+use cosmwasm_std::{attr, testing::mock_info, Decimal256, Env, Response};
+use neutron_sdk::bindings::msg::NeutronMsg;
+use std::str::FromStr;
+
+const RATE_SOURCE: &str = "0x00000000000000000000000000000000000000aa";
+
+fn setup() -> (MockDeps, Env) {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    set_gmp_config(deps.as_mut(), env.clone(), "admin").unwrap();
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("admin", &[]),
+        ExecuteMsg::SetExchangeRateSource {
+            source_chain: "ethereum".to_string(),
+            source_address: RATE_SOURCE.to_string(),
+        },
+    )
+    .unwrap();
+    (deps, env)
+}
+
+fn update(
+    deps: &mut MockDeps,
+    env: &Env,
+    sender: &str,
+    source_address: &str,
+    rate: &str,
+    block_number: u64,
+) -> ContractResult<Response<NeutronMsg>> {
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(sender, &[]),
+        ExecuteMsg::UpdateExchangeRate {
+            source_chain: "ethereum".to_string(),
+            source_address: source_address.to_string(),
+            rate: Decimal256::from_str(rate).unwrap(),
+            block_number,
+            block_timestamp: env.block.time.seconds() - 60,
+        },
+    )
+}
+
+#[test]
+fn success() {
+    let (mut deps, env) = setup();
+    let response = update(&mut deps, &env, "axelar_relayer", RATE_SOURCE, "1.15", 100).unwrap();
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "update_exchange_rate"),
+            attr("rate", "1.15"),
+            attr("block_number", "100"),
+            attr(
+                "block_timestamp",
+                (env.block.time.seconds() - 60).to_string()
+            ),
+        ]
+    );
+    let exchange_rate = EXCHANGE_RATE.load(deps.as_ref().storage).unwrap();
+    assert_eq!(exchange_rate.rate, Decimal256::from_str("1.15").unwrap());
+    assert_eq!(exchange_rate.updated_at, env.block.time);
+
+    update(&mut deps, &env, "axelar_relayer", RATE_SOURCE, "1.16", 101).unwrap();
+    let exchange_rate = EXCHANGE_RATE.load(deps.as_ref().storage).unwrap();
+    assert_eq!(exchange_rate.rate, Decimal256::from_str("1.16").unwrap());
+    assert_eq!(exchange_rate.block_number, 101);
+}
+
+#[test]
+fn not_from_gmp() {
+    let (mut deps, env) = setup();
+    let err = update(&mut deps, &env, "stranger", RATE_SOURCE, "1.15", 100).unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+}
+
+#[test]
+fn not_from_rate_source() {
+    let (mut deps, env) = setup();
+    let err = update(
+        &mut deps,
+        &env,
+        "axelar_relayer",
+        "0x00000000000000000000000000000000000000bb",
+        "1.15",
+        100,
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+}
+
+#[test]
+fn rate_source_not_configured() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    let err = update(&mut deps, &env, "axelar_relayer", RATE_SOURCE, "1.15", 100).unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+}
+
+#[test]
+fn zero_rate() {
+    let (mut deps, env) = setup();
+    let err = update(&mut deps, &env, "axelar_relayer", RATE_SOURCE, "0", 100).unwrap_err();
+    assert_eq!(err, ContractError::ZeroExchangeRate {});
+}
+
+#[test]
+fn non_monotonic() {
+    let (mut deps, env) = setup();
+    update(&mut deps, &env, "axelar_relayer", RATE_SOURCE, "1.15", 100).unwrap();
+    let err = update(&mut deps, &env, "axelar_relayer", RATE_SOURCE, "1.16", 100).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::NonMonotonicExchangeRate {
+            block_number: 100,
+            last_block_number: 100,
+        }
+    );
+}
+
+#[test]
+fn stale() {
+    let (mut deps, env) = setup();
+    let block_timestamp = env.block.time.seconds() - MAX_EXCHANGE_RATE_AGE - 1;
+    let err = execute(
+        deps.as_mut(),
+        env,
+        mock_info("axelar_relayer", &[]),
+        ExecuteMsg::UpdateExchangeRate {
+            source_chain: "ethereum".to_string(),
+            source_address: RATE_SOURCE.to_string(),
+            rate: Decimal256::from_str("1.15").unwrap(),
+            block_number: 100,
+            block_timestamp,
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::StaleExchangeRate {
+            block_timestamp,
+            max_age: MAX_EXCHANGE_RATE_AGE,
+        }
+    );
+}
+
+#[test]
+fn from_future() {
+    let (mut deps, env) = setup();
+    let now = env.block.time.seconds();
+    let update_at = |deps: &mut MockDeps, block_number, block_timestamp| {
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("axelar_relayer", &[]),
+            ExecuteMsg::UpdateExchangeRate {
+                source_chain: "ethereum".to_string(),
+                source_address: RATE_SOURCE.to_string(),
+                rate: Decimal256::from_str("1.15").unwrap(),
+                block_number,
+                block_timestamp,
+            },
+        )
+    };
+
+    // small clock drift is tolerated
+    update_at(&mut deps, 100, now + MAX_EXCHANGE_RATE_CLOCK_SKEW).unwrap();
+
+    let block_timestamp = now + MAX_EXCHANGE_RATE_CLOCK_SKEW + 1;
+    let err = update_at(&mut deps, 101, block_timestamp).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::FutureExchangeRate {
+            block_timestamp,
+            now,
+            max_skew: MAX_EXCHANGE_RATE_CLOCK_SKEW,
+        }
+    );
+}
+
+#[test]
+fn set_source_by_stranger() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    let err = execute(
+        deps.as_mut(),
+        env,
+        mock_info("stranger", &[]),
+        ExecuteMsg::SetExchangeRateSource {
+            source_chain: "ethereum".to_string(),
+            source_address: RATE_SOURCE.to_string(),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+}
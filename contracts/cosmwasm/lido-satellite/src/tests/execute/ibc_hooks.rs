@@ -0,0 +1,133 @@
+use crate::{
+    contract::execute,
+    ibc_hooks::{derive_intermediate_sender, IbcHooksOrigin},
+    msg::ExecuteMsg,
+    tests::helpers::{instantiate_wrapper, MockDeps, VALID_IBC_DENOM},
+    ContractError, ContractResult,
+};
+use cosmwasm_std::{attr, coin, testing::mock_info, Env, Response, Uint128};
+use neutron_sdk::bindings::msg::NeutronMsg;
+
+const ORIGINAL_SENDER: &str = "osmo1qypqxpq9qcrsszg2pvxq6rs0zqg3yyc5helwsw";
+
+/// Intermediate sender ibc-hooks derives for [`ORIGINAL_SENDER`] on `channel-10`
+const INTERMEDIATE_SENDER: &str =
+    "neutron15eqhc45y3c7twdf4actlvz245szkcuq86y8qhr59ua2c95nmz3wshyxlcm";
+
+fn origin(original_sender: &str) -> IbcHooksOrigin {
+    IbcHooksOrigin {
+        channel: "channel-10".to_string(),
+        original_sender: original_sender.to_string(),
+    }
+}
+
+fn mint(
+    deps: &mut MockDeps,
+    env: &Env,
+    sender: &str,
+    receiver: Option<&str>,
+    origin: IbcHooksOrigin,
+) -> ContractResult<Response<NeutronMsg>> {
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(sender, &[coin(10, VALID_IBC_DENOM)]),
+        ExecuteMsg::Mint {
+            receiver: receiver.map(str::to_string),
+            idempotency_key: None,
+            source_chain: None,
+            source_address: None,
+            evm_sender: None,
+            ibc_hooks_origin: Some(origin),
+            referral: None,
+            memo: None,
+        },
+    )
+}
+
+#[test]
+fn derivation() {
+    assert_eq!(
+        derive_intermediate_sender("channel-10", ORIGINAL_SENDER).unwrap(),
+        INTERMEDIATE_SENDER
+    );
+}
+
+#[test]
+fn receiver_defaults_to_original_sender() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    let response = mint(
+        &mut deps,
+        &env,
+        INTERMEDIATE_SENDER,
+        None,
+        origin(ORIGINAL_SENDER),
+    )
+    .unwrap();
+    assert_eq!(
+        response.messages[0].msg,
+        NeutronMsg::submit_mint_tokens("eth", Uint128::new(10), ORIGINAL_SENDER).into()
+    );
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "mint"),
+            attr("amount", "10"),
+            attr("sender", INTERMEDIATE_SENDER),
+            attr("receiver", ORIGINAL_SENDER),
+            attr("original_sender", ORIGINAL_SENDER),
+            attr("ibc_channel", "channel-10"),
+        ]
+    );
+}
+
+#[test]
+fn invalid_original_sender_requires_receiver() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    let original_sender = "0x00000000000000000000000000000000000000EE";
+    let sender = derive_intermediate_sender("channel-10", original_sender).unwrap();
+    let err = mint(&mut deps, &env, &sender, None, origin(original_sender)).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::IbcHooksReceiverRequired {
+            original_sender: original_sender.to_string(),
+        }
+    );
+
+    let response = mint(
+        &mut deps,
+        &env,
+        &sender,
+        Some("benefitiary"),
+        origin(original_sender),
+    )
+    .unwrap();
+    assert_eq!(response.attributes[3], attr("receiver", "benefitiary"));
+}
+
+#[test]
+fn explicit_receiver() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    let response = mint(
+        &mut deps,
+        &env,
+        INTERMEDIATE_SENDER,
+        Some("benefitiary"),
+        origin(ORIGINAL_SENDER),
+    )
+    .unwrap();
+    assert_eq!(response.attributes[3], attr("receiver", "benefitiary"));
+}
+
+#[test]
+fn sender_mismatch() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    let err = mint(&mut deps, &env, "stranger", None, origin(ORIGINAL_SENDER)).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::IbcHooksSenderMismatch {
+            expected: INTERMEDIATE_SENDER.to_string(),
+            actual: "stranger".to_string(),
+        }
+    );
+}
@@ -0,0 +1,134 @@
+use crate::{
+    contract::execute,
+    msg::{ExecuteMsg, IDEMPOTENCY_KEY_TTL},
+    state::{IDEMPOTENCY_KEYS, IDEMPOTENCY_KEY_EXPIRATIONS},
+    tests::helpers::{instantiate_wrapper, MockDeps, VALID_IBC_DENOM},
+    ContractError,
+};
+use cosmwasm_std::{attr, coin, testing::mock_info, Addr, Env, Order, Response};
+use neutron_sdk::bindings::msg::NeutronMsg;
+
+fn mint_with_key(
+    deps: &mut MockDeps,
+    env: Env,
+    key: &str,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    mint_with_key_by(deps, env, "stranger", key)
+}
+
+fn mint_with_key_by(
+    deps: &mut MockDeps,
+    env: Env,
+    sender: &str,
+    key: &str,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    execute(
+        deps.as_mut(),
+        env,
+        mock_info(sender, &[coin(10, VALID_IBC_DENOM)]),
+        ExecuteMsg::Mint {
+            receiver: None,
+            idempotency_key: Some(key.to_string()),
+            source_chain: None,
+            source_address: None,
+            evm_sender: None,
+            ibc_hooks_origin: None,
+            referral: None,
+            memo: None,
+        },
+    )
+}
+
+#[test]
+fn first_use() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    let response = mint_with_key(&mut deps, env.clone(), "command-1").unwrap();
+    assert_eq!(
+        response.attributes.last().unwrap(),
+        attr("idempotency_key", "command-1")
+    );
+    assert_eq!(
+        IDEMPOTENCY_KEYS
+            .load(
+                deps.as_ref().storage,
+                (&Addr::unchecked("stranger"), "command-1")
+            )
+            .unwrap(),
+        env.block.time.plus_seconds(IDEMPOTENCY_KEY_TTL)
+    );
+}
+
+#[test]
+fn duplicate() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    mint_with_key(&mut deps, env.clone(), "command-1").unwrap();
+    mint_with_key(&mut deps, env.clone(), "command-2").unwrap();
+    let err = mint_with_key(&mut deps, env, "command-1").unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::DuplicateIdempotencyKey {
+            key: "command-1".to_string()
+        }
+    );
+}
+
+#[test]
+fn same_key_of_another_sender() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    // a key taken up by someone else does not block the relayer which owns it
+    mint_with_key_by(&mut deps, env.clone(), "front_runner", "command-1").unwrap();
+    mint_with_key_by(&mut deps, env.clone(), "relayer", "command-1").unwrap();
+    let err = mint_with_key_by(&mut deps, env, "relayer", "command-1").unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::DuplicateIdempotencyKey {
+            key: "command-1".to_string()
+        }
+    );
+}
+
+#[test]
+fn empty_key() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    let err = mint_with_key(&mut deps, env, "").unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidIdempotencyKey {
+            reason: "key should not be empty".to_string()
+        }
+    );
+}
+
+#[test]
+fn too_long_key() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    let err = mint_with_key(&mut deps, env, &"a".repeat(129)).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidIdempotencyKey {
+            reason: "key should not be longer than 128 bytes".to_string()
+        }
+    );
+}
+
+#[test]
+fn expired_key_is_pruned_and_reusable() {
+    let (_result, mut deps, mut env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    mint_with_key(&mut deps, env.clone(), "command-1").unwrap();
+
+    env.block.time = env.block.time.plus_seconds(IDEMPOTENCY_KEY_TTL);
+    mint_with_key(&mut deps, env.clone(), "command-2").unwrap();
+    // the first key has been pruned by the second mint
+    assert!(!IDEMPOTENCY_KEYS.has(
+        deps.as_ref().storage,
+        (&Addr::unchecked("stranger"), "command-1")
+    ));
+    assert_eq!(
+        IDEMPOTENCY_KEY_EXPIRATIONS
+            .keys(deps.as_ref().storage, None, None, Order::Ascending)
+            .count(),
+        1
+    );
+
+    mint_with_key(&mut deps, env, "command-1").unwrap();
+}
@@ -0,0 +1,406 @@
+use crate::{
+    contract::{execute, query},
+    msg::{BurnIntent, ExecuteMsg, IntentAccountResponse, QueryMsg, WithdrawalResponse},
+    state::WithdrawalQueueConfig,
+    tests::helpers::{instantiate_wrapper, MockDeps, VALID_IBC_DENOM},
+    ContractError,
+};
+use cosmwasm_std::{attr, coin, from_json, testing::mock_info, BankMsg, Binary, Env, Uint128};
+use k256::ecdsa::{signature::hazmat::PrehashSigner, Signature, SigningKey};
+use neutron_sdk::bindings::msg::NeutronMsg;
+
+fn signing_key() -> SigningKey {
+    SigningKey::from_bytes(&[7u8; 32].into()).unwrap()
+}
+
+fn pubkey(key: &SigningKey) -> Binary {
+    key.verifying_key()
+        .to_encoded_point(true)
+        .as_bytes()
+        .to_vec()
+        .into()
+}
+
+fn sign(deps: &MockDeps, env: &Env, key: &SigningKey, intent: &BurnIntent) -> Binary {
+    let digest: Binary = from_json(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::BurnIntentDigest {
+                intent: intent.clone(),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    let signature: Signature = key.sign_prehash(&digest).unwrap();
+    signature.to_bytes().to_vec().into()
+}
+
+fn intent(env: &Env, nonce: u64) -> BurnIntent {
+    BurnIntent {
+        owner: "depositor".to_string(),
+        canonical_denom: "eth".to_string(),
+        amount: Uint128::new(100),
+        receiver: "benefitiary".to_string(),
+        nonce,
+        expires_at: env.block.time.plus_seconds(60),
+        max_relayer_fee: Uint128::new(5),
+    }
+}
+
+fn setup() -> (MockDeps, Env, SigningKey) {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    deps.querier
+        .base
+        .update_balance(&env.contract.address, vec![coin(1000, VALID_IBC_DENOM)]);
+    let key = signing_key();
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("depositor", &[coin(300, "eth")]),
+        ExecuteMsg::DepositForIntents {
+            pubkey: pubkey(&key),
+        },
+    )
+    .unwrap();
+    (deps, env, key)
+}
+
+fn execute_intent(
+    deps: &mut MockDeps,
+    env: &Env,
+    intent: BurnIntent,
+    signature: Binary,
+    relayer_fee: u128,
+) -> Result<cosmwasm_std::Response<NeutronMsg>, ContractError> {
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("relayer", &[]),
+        ExecuteMsg::ExecuteBurnIntent {
+            intent,
+            signature,
+            relayer_fee: Uint128::new(relayer_fee),
+        },
+    )
+}
+
+#[test]
+fn deposit_invalid_pubkey() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    let err = execute(
+        deps.as_mut(),
+        env,
+        mock_info("depositor", &[coin(300, "eth")]),
+        ExecuteMsg::DepositForIntents {
+            pubkey: vec![2u8; 32].into(),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::InvalidPublicKey {});
+}
+
+#[test]
+fn deposit_without_canonical_funds() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    let err = execute(
+        deps.as_mut(),
+        env,
+        mock_info("depositor", &[coin(300, VALID_IBC_DENOM)]),
+        ExecuteMsg::DepositForIntents {
+            pubkey: pubkey(&signing_key()),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::UnexpectedFunds {
+            denom: VALID_IBC_DENOM.to_string(),
+        }
+    );
+}
+
+#[test]
+fn deposit_and_withdraw() {
+    let (mut deps, env, key) = setup();
+    let response = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("depositor", &[]),
+        ExecuteMsg::WithdrawIntentDeposit {
+            denom: "eth".to_string(),
+            amount: Uint128::new(100),
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        response.messages[0].msg,
+        BankMsg::Send {
+            to_address: "depositor".to_string(),
+            amount: vec![coin(100, "eth")],
+        }
+        .into()
+    );
+
+    let account: IntentAccountResponse = from_json(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::IntentAccount {
+                address: "depositor".to_string(),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        account,
+        IntentAccountResponse {
+            pubkey: pubkey(&key),
+            next_nonce: 0,
+            deposits: vec![coin(200, "eth")],
+        }
+    );
+
+    let err = execute(
+        deps.as_mut(),
+        env,
+        mock_info("depositor", &[]),
+        ExecuteMsg::WithdrawIntentDeposit {
+            denom: "eth".to_string(),
+            amount: Uint128::new(201),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InsufficientIntentDeposit {
+            denom: "eth".to_string(),
+            available: Uint128::new(200),
+            requested: Uint128::new(201),
+        }
+    );
+}
+
+#[test]
+fn success() {
+    let (mut deps, env, key) = setup();
+    let intent = intent(&env, 0);
+    let signature = sign(&deps, &env, &key, &intent);
+    let response = execute_intent(&mut deps, &env, intent, signature, 3).unwrap();
+
+    let messages = response
+        .messages
+        .into_iter()
+        .map(|submsg| submsg.msg)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        messages,
+        vec![
+            NeutronMsg::BurnTokens {
+                denom: "eth".to_string(),
+                amount: Uint128::new(100),
+                burn_from_address: "".to_string(),
+            }
+            .into(),
+            BankMsg::Send {
+                to_address: "benefitiary".to_string(),
+                amount: vec![coin(97, VALID_IBC_DENOM)],
+            }
+            .into(),
+            BankMsg::Send {
+                to_address: "relayer".to_string(),
+                amount: vec![coin(3, VALID_IBC_DENOM)],
+            }
+            .into(),
+        ]
+    );
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "burn_intent"),
+            attr("amount", "100"),
+            attr("owner", "depositor"),
+            attr("receiver", "benefitiary"),
+            attr("nonce", "0"),
+            attr("relayer", "relayer"),
+            attr("relayer_fee", "3"),
+        ]
+    );
+
+    let account: IntentAccountResponse = from_json(
+        query(
+            deps.as_ref(),
+            env,
+            QueryMsg::IntentAccount {
+                address: "depositor".to_string(),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(account.next_nonce, 1);
+    assert_eq!(account.deposits, vec![coin(200, "eth")]);
+}
+
+#[test]
+fn large_intent_is_queued() {
+    let (mut deps, mut env, key) = setup();
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("admin", &[]),
+        ExecuteMsg::SetWithdrawalQueue {
+            canonical_subdenom: "eth".to_string(),
+            config: Some(WithdrawalQueueConfig {
+                threshold: Uint128::new(50),
+                delay: 60 * 60,
+            }),
+        },
+    )
+    .unwrap();
+    let intent = intent(&env, 0);
+    let signature = sign(&deps, &env, &key, &intent);
+    let response = execute_intent(&mut deps, &env, intent, signature, 3).unwrap();
+
+    // only the relayer is paid out right away
+    assert_eq!(response.messages.len(), 2);
+    assert_eq!(
+        response.messages[1].msg,
+        BankMsg::Send {
+            to_address: "relayer".to_string(),
+            amount: vec![coin(3, VALID_IBC_DENOM)],
+        }
+        .into()
+    );
+    let release_at = env.block.time.plus_seconds(60 * 60);
+    assert_eq!(
+        response.attributes[7..],
+        [
+            attr("withdrawal_id", "0"),
+            attr("release_at", release_at.to_string()),
+        ]
+    );
+    let withdrawals: Vec<WithdrawalResponse> = from_json(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::Withdrawals {
+                receiver: "benefitiary".to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        withdrawals,
+        vec![WithdrawalResponse {
+            id: 0,
+            sender: "depositor".to_string(),
+            receiver: "benefitiary".to_string(),
+            canonical_denom: "eth".to_string(),
+            amount: Uint128::new(97),
+            payout: vec![coin(97, VALID_IBC_DENOM)],
+            release_at,
+            evm_transfer: None,
+        }]
+    );
+
+    env.block.time = release_at;
+    let response = execute(
+        deps.as_mut(),
+        env,
+        mock_info("anyone", &[]),
+        ExecuteMsg::ClaimWithdrawal { id: 0 },
+    )
+    .unwrap();
+    assert_eq!(
+        response.messages[0].msg,
+        BankMsg::Send {
+            to_address: "benefitiary".to_string(),
+            amount: vec![coin(97, VALID_IBC_DENOM)],
+        }
+        .into()
+    );
+}
+
+#[test]
+fn replay() {
+    let (mut deps, env, key) = setup();
+    let intent = intent(&env, 0);
+    let signature = sign(&deps, &env, &key, &intent);
+    execute_intent(&mut deps, &env, intent.clone(), signature.clone(), 0).unwrap();
+    let err = execute_intent(&mut deps, &env, intent, signature, 0).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidNonce {
+            expected: 1,
+            actual: 0
+        }
+    );
+}
+
+#[test]
+fn invalid_signature() {
+    let (mut deps, env, key) = setup();
+    let intent = intent(&env, 0);
+    let signature = sign(&deps, &env, &key, &intent);
+    let tampered = BurnIntent {
+        receiver: "attacker".to_string(),
+        ..intent
+    };
+    let err = execute_intent(&mut deps, &env, tampered, signature, 0).unwrap_err();
+    assert_eq!(err, ContractError::InvalidSignature {});
+}
+
+#[test]
+fn expired() {
+    let (mut deps, mut env, key) = setup();
+    let intent = intent(&env, 0);
+    let signature = sign(&deps, &env, &key, &intent);
+    env.block.time = env.block.time.plus_seconds(61);
+    let err = execute_intent(&mut deps, &env, intent.clone(), signature, 0).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::IntentExpired {
+            expires_at: intent.expires_at
+        }
+    );
+}
+
+#[test]
+fn relayer_fee_too_high() {
+    let (mut deps, env, key) = setup();
+    let intent = intent(&env, 0);
+    let signature = sign(&deps, &env, &key, &intent);
+    let err = execute_intent(&mut deps, &env, intent, signature, 6).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::RelayerFeeTooHigh {
+            fee: Uint128::new(6),
+            max: Uint128::new(5),
+        }
+    );
+}
+
+#[test]
+fn insufficient_deposit() {
+    let (mut deps, env, key) = setup();
+    let intent = BurnIntent {
+        amount: Uint128::new(301),
+        ..intent(&env, 0)
+    };
+    let signature = sign(&deps, &env, &key, &intent);
+    let err = execute_intent(&mut deps, &env, intent, signature, 0).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InsufficientIntentDeposit {
+            denom: "eth".to_string(),
+            available: Uint128::new(300),
+            requested: Uint128::new(301),
+        }
+    );
+}
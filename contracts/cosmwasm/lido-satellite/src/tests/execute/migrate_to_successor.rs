@@ -0,0 +1,199 @@
+use crate::{
+    contract::{execute, query},
+    msg::{ConfigResponse, ExecuteMsg, QueryMsg},
+    tests::helpers::{
+        instantiate_wrapper, mint_msg, register_asset_wrapper, VALID_IBC_DENOM, VALID_IBC_DENOM_2,
+    },
+    ContractError,
+};
+use cosmwasm_std::{attr, coin, from_json, testing::mock_info, BankMsg, Event};
+use neutron_sdk::bindings::msg::NeutronMsg;
+
+#[test]
+fn stranger() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    let err = execute(
+        deps.as_mut(),
+        env,
+        mock_info("stranger", &[]),
+        ExecuteMsg::MigrateToSuccessor {
+            successor: "successor".to_string(),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+}
+
+#[test]
+fn without_reserves() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    let response = execute(
+        deps.as_mut(),
+        env,
+        mock_info("admin", &[]),
+        ExecuteMsg::MigrateToSuccessor {
+            successor: "successor".to_string(),
+        },
+    )
+    .unwrap();
+    assert_eq!(response.messages.len(), 1);
+    assert_eq!(
+        response.messages[0].msg,
+        NeutronMsg::ChangeAdmin {
+            denom: "eth".to_string(),
+            new_admin_address: "successor".to_string(),
+        }
+        .into()
+    );
+    assert_eq!(
+        response.events,
+        vec![
+            Event::new("change_denom_admin")
+                .add_attributes([attr("denom", "eth"), attr("new_admin", "successor")]),
+            Event::new("disable_mint_and_burn"),
+        ]
+    );
+}
+
+#[test]
+fn with_reserves() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    deps.querier
+        .base
+        .update_balance(&env.contract.address, vec![coin(100, VALID_IBC_DENOM)]);
+    let response = execute(
+        deps.as_mut(),
+        env,
+        mock_info("admin", &[]),
+        ExecuteMsg::MigrateToSuccessor {
+            successor: "successor".to_string(),
+        },
+    )
+    .unwrap();
+    assert_eq!(response.messages.len(), 2);
+    assert_eq!(
+        response.messages[1].msg,
+        BankMsg::Send {
+            to_address: "successor".to_string(),
+            amount: vec![coin(100, VALID_IBC_DENOM)],
+        }
+        .into()
+    );
+    assert_eq!(
+        response.events[1],
+        Event::new("transfer_reserves").add_attributes([
+            attr("amount", "100"),
+            attr("denom", VALID_IBC_DENOM),
+            attr("receiver", "successor"),
+        ])
+    );
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "migrate_to_successor"),
+            attr("successor", "successor"),
+        ]
+    );
+}
+
+#[test]
+fn multiple_assets() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    register_asset_wrapper(deps.as_mut(), env.clone(), "steth", &[VALID_IBC_DENOM_2]);
+    deps.querier.base.update_balance(
+        &env.contract.address,
+        vec![coin(100, VALID_IBC_DENOM), coin(200, VALID_IBC_DENOM_2)],
+    );
+    let response = execute(
+        deps.as_mut(),
+        env,
+        mock_info("admin", &[]),
+        ExecuteMsg::MigrateToSuccessor {
+            successor: "successor".to_string(),
+        },
+    )
+    .unwrap();
+    let messages = response
+        .messages
+        .into_iter()
+        .map(|submsg| submsg.msg)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        messages,
+        vec![
+            NeutronMsg::ChangeAdmin {
+                denom: "eth".to_string(),
+                new_admin_address: "successor".to_string(),
+            }
+            .into(),
+            BankMsg::Send {
+                to_address: "successor".to_string(),
+                amount: vec![coin(100, VALID_IBC_DENOM)],
+            }
+            .into(),
+            NeutronMsg::ChangeAdmin {
+                denom: "steth".to_string(),
+                new_admin_address: "successor".to_string(),
+            }
+            .into(),
+            BankMsg::Send {
+                to_address: "successor".to_string(),
+                amount: vec![coin(200, VALID_IBC_DENOM_2)],
+            }
+            .into(),
+        ]
+    );
+}
+
+#[test]
+fn disables_mint_and_burn() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("admin", &[]),
+        ExecuteMsg::MigrateToSuccessor {
+            successor: "successor".to_string(),
+        },
+    )
+    .unwrap();
+
+    let migrated = ContractError::Migrated {
+        successor: "successor".to_string(),
+    };
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("stranger", &[coin(10, VALID_IBC_DENOM)]),
+        mint_msg(None),
+    )
+    .unwrap_err();
+    assert_eq!(err, migrated);
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("stranger", &[coin(10, "eth")]),
+        ExecuteMsg::Burn {
+            receiver: None,
+            bridged_denom: None,
+            referral: None,
+            memo: None,
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err, migrated);
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("admin", &[]),
+        ExecuteMsg::MigrateToSuccessor {
+            successor: "another_successor".to_string(),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err, migrated);
+
+    let config_response: ConfigResponse =
+        from_json(query(deps.as_ref(), env, QueryMsg::Config {}).unwrap()).unwrap();
+    assert_eq!(config_response.successor, Some("successor".to_string()));
+}
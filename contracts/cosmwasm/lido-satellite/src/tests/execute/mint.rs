@@ -1,7 +1,9 @@
 use crate::{
     contract::execute,
-    msg::ExecuteMsg,
-    tests::helpers::{instantiate_wrapper, VALID_IBC_DENOM},
+    execute::FEE_DENOM,
+    tests::helpers::{
+        instantiate_wrapper, mint_msg, register_asset_wrapper, VALID_IBC_DENOM, VALID_IBC_DENOM_2,
+    },
     ContractError,
 };
 use cosmwasm_std::{attr, coin, testing::mock_info, Response, Uint128};
@@ -14,7 +16,7 @@ fn no_funds() {
         deps.as_mut(),
         env,
         mock_info("stranger", &[]),
-        ExecuteMsg::Mint { receiver: None },
+        mint_msg(None),
     )
     .unwrap_err();
     assert_eq!(err, ContractError::NothingToMint {});
@@ -27,10 +29,15 @@ fn incorrect_funds() {
         deps.as_mut(),
         env,
         mock_info("stranger", &[coin(10, "ldo")]),
-        ExecuteMsg::Mint { receiver: None },
+        mint_msg(None),
     )
     .unwrap_err();
-    assert_eq!(err, ContractError::NothingToMint {});
+    assert_eq!(
+        err,
+        ContractError::UnexpectedFunds {
+            denom: "ldo".to_string(),
+        }
+    );
 }
 
 #[test]
@@ -40,7 +47,7 @@ fn correct_funds() {
         deps.as_mut(),
         env,
         mock_info("stranger", &[coin(10, VALID_IBC_DENOM)]),
-        ExecuteMsg::Mint { receiver: None },
+        mint_msg(None),
     )
     .unwrap();
     assert_mint_message_and_attrs(&response, "stranger", "stranger", 10, "eth");
@@ -53,7 +60,69 @@ fn mixed_funds() {
         deps.as_mut(),
         env,
         mock_info("stranger", &[coin(10, VALID_IBC_DENOM), coin(20, "ldo")]),
-        ExecuteMsg::Mint { receiver: None },
+        mint_msg(None),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::UnexpectedFunds {
+            denom: "ldo".to_string(),
+        }
+    );
+}
+
+#[test]
+fn fee_funds() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    let err = execute(
+        deps.as_mut(),
+        env,
+        mock_info("stranger", &[coin(10, VALID_IBC_DENOM), coin(5, FEE_DENOM)]),
+        mint_msg(None),
+    )
+    .unwrap_err();
+    // nothing in a mint consumes fee funds, so they are not accepted
+    assert_eq!(
+        err,
+        ContractError::UnexpectedFunds {
+            denom: FEE_DENOM.to_string(),
+        }
+    );
+}
+
+#[test]
+fn duplicate_funds() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    let err = execute(
+        deps.as_mut(),
+        env,
+        mock_info(
+            "stranger",
+            &[coin(10, VALID_IBC_DENOM), coin(10, VALID_IBC_DENOM)],
+        ),
+        mint_msg(None),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::DuplicateFunds {
+            denom: VALID_IBC_DENOM.to_string(),
+        }
+    );
+}
+
+#[test]
+fn funds_of_two_bridged_denoms() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    register_asset_wrapper(deps.as_mut(), env.clone(), "btc", &[VALID_IBC_DENOM_2]);
+    let err = execute(
+        deps.as_mut(),
+        env,
+        mock_info(
+            "stranger",
+            &[coin(10, VALID_IBC_DENOM), coin(10, VALID_IBC_DENOM_2)],
+        ),
+        mint_msg(None),
     )
     .unwrap_err();
     assert_eq!(err, ContractError::ExtraFunds {});
@@ -66,14 +135,34 @@ fn with_custom_receiver() {
         deps.as_mut(),
         env,
         mock_info("stranger", &[coin(11, VALID_IBC_DENOM)]),
-        ExecuteMsg::Mint {
-            receiver: Some("benefitiary".to_string()),
-        },
+        mint_msg(Some("benefitiary")),
     )
     .unwrap();
     assert_mint_message_and_attrs(&response, "stranger", "benefitiary", 11, "eth");
 }
 
+#[test]
+fn routed_by_bridged_denom() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    register_asset_wrapper(deps.as_mut(), env.clone(), "steth", &[VALID_IBC_DENOM_2]);
+    let response = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("stranger", &[coin(13, VALID_IBC_DENOM_2)]),
+        mint_msg(None),
+    )
+    .unwrap();
+    assert_mint_message_and_attrs(&response, "stranger", "stranger", 13, "steth");
+    let response = execute(
+        deps.as_mut(),
+        env,
+        mock_info("stranger", &[coin(14, VALID_IBC_DENOM)]),
+        mint_msg(None),
+    )
+    .unwrap();
+    assert_mint_message_and_attrs(&response, "stranger", "stranger", 14, "eth");
+}
+
 fn assert_mint_message_and_attrs(
     response: &Response<NeutronMsg>,
     sender: &str,
@@ -0,0 +1,113 @@
+use crate::{
+    contract::execute,
+    msg::{ExecuteMsg, MAX_MINT_BATCH_SIZE},
+    tests::helpers::{instantiate_wrapper, MockDeps, VALID_IBC_DENOM},
+    ContractError,
+};
+use cosmwasm_std::{
+    attr, coin,
+    testing::{mock_env, mock_info},
+    Event, Response, Uint128,
+};
+use neutron_sdk::bindings::msg::NeutronMsg;
+
+fn mint_batch(
+    deps: &mut MockDeps,
+    deposited: u128,
+    outputs: &[(&str, u128)],
+) -> Result<Response<NeutronMsg>, ContractError> {
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("stranger", &[coin(deposited, VALID_IBC_DENOM)]),
+        ExecuteMsg::MintBatch {
+            outputs: outputs
+                .iter()
+                .map(|(receiver, amount)| (receiver.to_string(), Uint128::new(*amount)))
+                .collect(),
+        },
+    )
+}
+
+#[test]
+fn splits_deposit() {
+    let (_result, mut deps, _env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    let response = mint_batch(&mut deps, 30, &[("alice", 10), ("bob", 20)]).unwrap();
+    assert_eq!(
+        response
+            .messages
+            .iter()
+            .map(|m| m.msg.clone())
+            .collect::<Vec<_>>(),
+        vec![
+            NeutronMsg::MintTokens {
+                denom: "eth".to_string(),
+                amount: Uint128::new(10),
+                mint_to_address: "alice".to_string(),
+            }
+            .into(),
+            NeutronMsg::MintTokens {
+                denom: "eth".to_string(),
+                amount: Uint128::new(20),
+                mint_to_address: "bob".to_string(),
+            }
+            .into(),
+        ]
+    );
+    assert_eq!(
+        response.events,
+        vec![
+            Event::new("mint_output").add_attributes([("receiver", "alice"), ("amount", "10")]),
+            Event::new("mint_output").add_attributes([("receiver", "bob"), ("amount", "20")]),
+        ]
+    );
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "mint_batch"),
+            attr("amount", "30"),
+            attr("sender", "stranger"),
+            attr("outputs", "2"),
+        ]
+    );
+}
+
+#[test]
+fn amount_mismatch() {
+    let (_result, mut deps, _env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    let err = mint_batch(&mut deps, 31, &[("alice", 10), ("bob", 20)]).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::MintBatchAmountMismatch {
+            total: Uint128::new(30),
+            deposited: Uint128::new(31),
+        }
+    );
+}
+
+#[test]
+fn invalid_outputs() {
+    let (_result, mut deps, _env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    let err = mint_batch(&mut deps, 10, &[]).unwrap_err();
+    assert_eq!(err, ContractError::EmptyMintBatch {});
+
+    let err = mint_batch(&mut deps, 10, &[("alice", 10), ("bob", 0)]).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::ZeroMintOutput {
+            receiver: "bob".to_string(),
+        }
+    );
+
+    mint_batch(&mut deps, 10, &[("alice", 5), ("Invalid", 5)]).unwrap_err();
+
+    let outputs = vec![("alice", 1); MAX_MINT_BATCH_SIZE + 1];
+    let err = mint_batch(&mut deps, outputs.len() as u128, &outputs).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::MintBatchTooLarge {
+            size: MAX_MINT_BATCH_SIZE + 1,
+            max_size: MAX_MINT_BATCH_SIZE,
+        }
+    );
+}
@@ -1,2 +1,22 @@
+mod add_bridged_denom;
+mod attest_supply;
 mod burn;
+mod burn_to_evm;
+mod exchange_rate;
+mod ibc_hooks;
+mod idempotency_key;
+mod intents;
+mod migrate_to_successor;
 mod mint;
+mod mint_batch;
+mod pending_claims;
+mod referral;
+mod register_asset;
+mod register_escrow_query;
+mod roles;
+mod set_amount_bounds;
+mod set_bridge_status;
+mod set_routing_policy;
+mod sweep_surplus;
+mod timelock;
+mod withdrawal_queue;
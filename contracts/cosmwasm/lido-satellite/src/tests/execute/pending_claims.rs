@@ -0,0 +1,434 @@
+use crate::{
+    contract::{execute, query},
+    msg::{ExecuteMsg, PendingClaimResponse, QueryMsg},
+    tests::helpers::{instantiate_wrapper, set_gmp_config, MockDeps, VALID_IBC_DENOM},
+    ContractError, ContractResult,
+};
+use cosmwasm_std::{
+    attr, coin, from_json, testing::mock_info, BankMsg, Binary, Env, Response, Uint128,
+};
+use k256::ecdsa::SigningKey;
+use neutron_sdk::bindings::msg::NeutronMsg;
+use sha3::{Digest, Keccak256};
+
+/// Address of a GMP Helper contract, which makes GMP calls on behalf of EVM accounts
+const GMP_HELPER: &str = "0x1f9090aae28b8a3dceadf281b0f12828e676c326";
+
+fn signing_key() -> SigningKey {
+    SigningKey::from_bytes(&[9u8; 32].into()).unwrap()
+}
+
+fn evm_address(key: &SigningKey) -> String {
+    let pubkey = key.verifying_key().to_encoded_point(false);
+    let hash = Keccak256::digest(&pubkey.as_bytes()[1..]);
+    format!("0x{}", hex::encode(&hash[12..]))
+}
+
+fn sign_claim(deps: &MockDeps, env: &Env, key: &SigningKey, receiver: &str) -> Binary {
+    let message: String = from_json(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::ClaimMessage {
+                evm_address: evm_address(key),
+                bridged_denom: VALID_IBC_DENOM.to_string(),
+                receiver: receiver.to_string(),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    let mut hasher = Keccak256::new();
+    hasher.update(format!(
+        "\x19Ethereum Signed Message:\n{}{}",
+        message.len(),
+        message
+    ));
+    let (signature, recovery_id) = key.sign_prehash_recoverable(&hasher.finalize()).unwrap();
+    let mut bytes = signature.to_bytes().to_vec();
+    bytes.push(recovery_id.to_byte() + 27);
+    bytes.into()
+}
+
+fn setup() -> (MockDeps, Env) {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    set_gmp_config(deps.as_mut(), env.clone(), "admin").unwrap();
+    (deps, env)
+}
+
+fn gmp_mint(
+    deps: &mut MockDeps,
+    env: &Env,
+    sender: &str,
+    source_address: &str,
+    evm_sender: Option<&str>,
+) -> ContractResult<Response<NeutronMsg>> {
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(sender, &[coin(40, VALID_IBC_DENOM)]),
+        ExecuteMsg::Mint {
+            receiver: Some("Invalid Receiver".to_string()),
+            idempotency_key: None,
+            source_chain: Some("ethereum".to_string()),
+            source_address: Some(source_address.to_string()),
+            evm_sender: evm_sender.map(str::to_string),
+            ibc_hooks_origin: None,
+            referral: None,
+            memo: None,
+        },
+    )
+}
+
+fn pending_claims(deps: &MockDeps, env: &Env, evm_address: &str) -> Vec<PendingClaimResponse> {
+    from_json(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::PendingClaims {
+                evm_address: evm_address.to_string(),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap()
+}
+
+#[test]
+fn invalid_receiver_escrowed() {
+    let (mut deps, env) = setup();
+    let address = evm_address(&signing_key());
+    let response = gmp_mint(&mut deps, &env, "axelar_relayer", &address, None).unwrap();
+    assert_eq!(
+        response.messages[0].msg,
+        NeutronMsg::submit_mint_tokens("eth", Uint128::new(40), &env.contract.address).into()
+    );
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "escrow_mint"),
+            attr("amount", "40"),
+            attr("sender", "axelar_relayer"),
+            attr("invalid_receiver", "Invalid Receiver"),
+            attr("source_chain", "ethereum"),
+            attr("source_address", &address),
+            attr("evm_address", &address),
+        ]
+    );
+
+    gmp_mint(
+        &mut deps,
+        &env,
+        "axelar_relayer",
+        &address.to_uppercase().replace("0X", "0x"),
+        None,
+    )
+    .unwrap();
+    assert_eq!(
+        pending_claims(&deps, &env, &address),
+        vec![PendingClaimResponse {
+            bridged_denom: VALID_IBC_DENOM.to_string(),
+            source_chain: "ethereum".to_string(),
+            canonical_denom: "eth".to_string(),
+            amount: Uint128::new(80),
+        }]
+    );
+}
+
+#[test]
+fn invalid_receiver_escrowed_for_helper_caller() {
+    let (mut deps, env) = setup();
+    let key = signing_key();
+    let address = evm_address(&key);
+    let response = gmp_mint(
+        &mut deps,
+        &env,
+        "axelar_relayer",
+        GMP_HELPER,
+        Some(&address.to_uppercase().replace("0X", "0x")),
+    )
+    .unwrap();
+    assert_eq!(
+        response.attributes[4..],
+        [
+            attr("source_chain", "ethereum"),
+            attr("source_address", GMP_HELPER),
+            attr("evm_address", &address),
+        ]
+    );
+    assert!(pending_claims(&deps, &env, GMP_HELPER).is_empty());
+    assert_eq!(pending_claims(&deps, &env, &address)[0].amount.u128(), 40);
+
+    let signature = sign_claim(&deps, &env, &key, "benefitiary");
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("stranger", &[]),
+        ExecuteMsg::ClaimPending {
+            evm_address: address.clone(),
+            bridged_denom: VALID_IBC_DENOM.to_string(),
+            receiver: "benefitiary".to_string(),
+            signature,
+        },
+    )
+    .unwrap();
+    assert!(pending_claims(&deps, &env, &address).is_empty());
+}
+
+#[test]
+fn invalid_evm_sender() {
+    let (mut deps, env) = setup();
+    let err = gmp_mint(
+        &mut deps,
+        &env,
+        "axelar_relayer",
+        GMP_HELPER,
+        Some("0x1234"),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidEvmAddress {
+            address: "0x1234".to_string(),
+            reason: "expected length of 42 chars".to_string(),
+        }
+    );
+}
+
+#[test]
+fn invalid_receiver_not_from_gmp() {
+    let (mut deps, env) = setup();
+    let address = evm_address(&signing_key());
+    gmp_mint(&mut deps, &env, "stranger", &address, None).unwrap_err();
+    assert!(pending_claims(&deps, &env, &address).is_empty());
+}
+
+#[test]
+fn invalid_source_address() {
+    let (mut deps, env) = setup();
+    let err = gmp_mint(&mut deps, &env, "axelar_relayer", "0x1234", None).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidEvmAddress {
+            address: "0x1234".to_string(),
+            reason: "expected length of 42 chars".to_string(),
+        }
+    );
+}
+
+#[test]
+fn claim() {
+    let (mut deps, env) = setup();
+    let key = signing_key();
+    let address = evm_address(&key);
+    gmp_mint(&mut deps, &env, "axelar_relayer", &address, None).unwrap();
+
+    let signature = sign_claim(&deps, &env, &key, "benefitiary");
+    let response = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("stranger", &[]),
+        ExecuteMsg::ClaimPending {
+            evm_address: address.clone(),
+            bridged_denom: VALID_IBC_DENOM.to_string(),
+            receiver: "benefitiary".to_string(),
+            signature,
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        response.messages[0].msg,
+        BankMsg::Send {
+            to_address: "benefitiary".to_string(),
+            amount: vec![coin(40, "eth")],
+        }
+        .into()
+    );
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "claim_pending"),
+            attr("amount", "40"),
+            attr("evm_address", &address),
+            attr("receiver", "benefitiary"),
+        ]
+    );
+    assert!(pending_claims(&deps, &env, &address).is_empty());
+}
+
+#[test]
+fn claim_after_migration() {
+    let (mut deps, env) = setup();
+    let key = signing_key();
+    let address = evm_address(&key);
+    gmp_mint(&mut deps, &env, "axelar_relayer", &address, None).unwrap();
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("admin", &[]),
+        ExecuteMsg::MigrateToSuccessor {
+            successor: "successor".to_string(),
+        },
+    )
+    .unwrap();
+
+    let signature = sign_claim(&deps, &env, &key, "benefitiary");
+    let err = execute(
+        deps.as_mut(),
+        env,
+        mock_info("stranger", &[]),
+        ExecuteMsg::ClaimPending {
+            evm_address: address,
+            bridged_denom: VALID_IBC_DENOM.to_string(),
+            receiver: "benefitiary".to_string(),
+            signature,
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Migrated {
+            successor: "successor".to_string(),
+        }
+    );
+}
+
+#[test]
+fn claim_signed_for_another_receiver() {
+    let (mut deps, env) = setup();
+    let key = signing_key();
+    let address = evm_address(&key);
+    gmp_mint(&mut deps, &env, "axelar_relayer", &address, None).unwrap();
+
+    let signature = sign_claim(&deps, &env, &key, "benefitiary");
+    let err = execute(
+        deps.as_mut(),
+        env,
+        mock_info("stranger", &[]),
+        ExecuteMsg::ClaimPending {
+            evm_address: address,
+            bridged_denom: VALID_IBC_DENOM.to_string(),
+            receiver: "stranger".to_string(),
+            signature,
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::InvalidSignature {});
+}
+
+#[test]
+fn claim_signed_for_another_amount() {
+    let (mut deps, env) = setup();
+    let key = signing_key();
+    let address = evm_address(&key);
+    gmp_mint(&mut deps, &env, "axelar_relayer", &address, None).unwrap();
+    let signature = sign_claim(&deps, &env, &key, "benefitiary");
+    gmp_mint(&mut deps, &env, "axelar_relayer", &address, None).unwrap();
+
+    let err = execute(
+        deps.as_mut(),
+        env,
+        mock_info("stranger", &[]),
+        ExecuteMsg::ClaimPending {
+            evm_address: address,
+            bridged_denom: VALID_IBC_DENOM.to_string(),
+            receiver: "benefitiary".to_string(),
+            signature,
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::InvalidSignature {});
+}
+
+#[test]
+fn claim_replayed() {
+    let (mut deps, env) = setup();
+    let key = signing_key();
+    let address = evm_address(&key);
+    gmp_mint(&mut deps, &env, "axelar_relayer", &address, None).unwrap();
+    let claim = ExecuteMsg::ClaimPending {
+        evm_address: address.clone(),
+        bridged_denom: VALID_IBC_DENOM.to_string(),
+        receiver: "benefitiary".to_string(),
+        signature: sign_claim(&deps, &env, &key, "benefitiary"),
+    };
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("stranger", &[]),
+        claim.clone(),
+    )
+    .unwrap();
+
+    // the same amount becomes pending again, but the nonce has been bumped
+    gmp_mint(&mut deps, &env, "axelar_relayer", &address, None).unwrap();
+    let err = execute(deps.as_mut(), env, mock_info("stranger", &[]), claim).unwrap_err();
+    assert_eq!(err, ContractError::InvalidSignature {});
+}
+
+#[test]
+fn claim_nothing_pending() {
+    let (mut deps, env) = setup();
+    let key = signing_key();
+    let address = evm_address(&key);
+    let err = execute(
+        deps.as_mut(),
+        env,
+        mock_info("stranger", &[]),
+        ExecuteMsg::ClaimPending {
+            evm_address: address.clone(),
+            bridged_denom: VALID_IBC_DENOM.to_string(),
+            receiver: "benefitiary".to_string(),
+            signature: Binary::from([0u8; 65]),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::PendingClaimNotFound {
+            evm_address: address,
+            bridged_denom: VALID_IBC_DENOM.to_string(),
+        }
+    );
+}
+
+#[test]
+fn return_by_stranger() {
+    let (mut deps, env) = setup();
+    let address = evm_address(&signing_key());
+    gmp_mint(&mut deps, &env, "axelar_relayer", &address, None).unwrap();
+    let err = execute(
+        deps.as_mut(),
+        env,
+        mock_info("stranger", &[]),
+        ExecuteMsg::ReturnPending {
+            evm_address: address,
+            bridged_denom: VALID_IBC_DENOM.to_string(),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+}
+
+#[test]
+fn return_without_gmp_config() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    let err = execute(
+        deps.as_mut(),
+        env,
+        mock_info("admin", &[]),
+        ExecuteMsg::ReturnPending {
+            evm_address: evm_address(&signing_key()),
+            bridged_denom: VALID_IBC_DENOM.to_string(),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::GmpNotConfigured {});
+}
+
+#[test]
+fn set_gmp_config_by_stranger() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    let err = set_gmp_config(deps.as_mut(), env, "stranger").unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+}
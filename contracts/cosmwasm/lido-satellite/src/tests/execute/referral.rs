@@ -0,0 +1,151 @@
+use crate::{
+    contract::{execute, query},
+    msg::{ExecuteMsg, QueryMsg, ReferralStatsResponse, MAX_MEMO_LENGTH},
+    state::ASSETS,
+    tests::helpers::{instantiate_wrapper, MockDeps, VALID_IBC_DENOM},
+    ContractError,
+};
+use cosmwasm_std::{attr, coin, from_json, testing::mock_info, Env, Response, Uint128};
+use neutron_sdk::bindings::msg::NeutronMsg;
+
+fn mint(
+    deps: &mut MockDeps,
+    env: &Env,
+    amount: u128,
+    referral: Option<&str>,
+    memo: Option<&str>,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("stranger", &[coin(amount, VALID_IBC_DENOM)]),
+        ExecuteMsg::Mint {
+            receiver: None,
+            idempotency_key: None,
+            source_chain: None,
+            source_address: None,
+            evm_sender: None,
+            ibc_hooks_origin: None,
+            referral: referral.map(str::to_string),
+            memo: memo.map(str::to_string),
+        },
+    )
+}
+
+#[test]
+fn mint_reports_referral_and_memo() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    let response = mint(&mut deps, &env, 10, Some("wallet-1"), Some("order #42")).unwrap();
+    assert_eq!(
+        response.attributes[response.attributes.len() - 2..],
+        [attr("referral", "wallet-1"), attr("memo", "order #42")]
+    );
+}
+
+#[test]
+fn aggregates_stats_per_referral() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    let asset = ASSETS.load(deps.as_mut().storage, "eth").unwrap();
+    mint(&mut deps, &env, 10, Some("wallet-1"), None).unwrap();
+    mint(&mut deps, &env, 20, Some("wallet-1"), None).unwrap();
+    mint(&mut deps, &env, 40, Some("wallet-2"), None).unwrap();
+    mint(&mut deps, &env, 80, None, None).unwrap();
+    deps.querier
+        .base
+        .update_balance(&env.contract.address, vec![coin(150, VALID_IBC_DENOM)]);
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("stranger", &[coin(5, asset.canonical_denom)]),
+        ExecuteMsg::Burn {
+            receiver: None,
+            bridged_denom: None,
+            referral: Some("wallet-1".to_string()),
+            memo: None,
+        },
+    )
+    .unwrap();
+
+    let stats: Vec<ReferralStatsResponse> = from_json(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::ReferralStats {
+                referral: "wallet-1".to_string(),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        stats,
+        vec![ReferralStatsResponse {
+            canonical_denom: "eth".to_string(),
+            minted: Uint128::new(30),
+            burned: Uint128::new(5),
+            mints: 2,
+            burns: 1,
+        }]
+    );
+
+    let stats: Vec<ReferralStatsResponse> = from_json(
+        query(
+            deps.as_ref(),
+            env,
+            QueryMsg::ReferralStats {
+                referral: "unknown".to_string(),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(stats, vec![]);
+}
+
+#[test]
+fn invalid_referral() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    for (referral, reason) in [
+        ("", "must not be empty"),
+        (&"a".repeat(33), "too long"),
+        (
+            "Wallet",
+            "only lowercase letters, digits, '-' and '_' are allowed",
+        ),
+    ] {
+        let err = mint(&mut deps, &env, 10, Some(referral), None).unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::InvalidReferral {
+                referral: referral.to_string(),
+                reason: reason.to_string(),
+            }
+        );
+    }
+}
+
+#[test]
+fn invalid_memo() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    let err = mint(
+        &mut deps,
+        &env,
+        10,
+        None,
+        Some(&"a".repeat(MAX_MEMO_LENGTH + 1)),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidMemo {
+            reason: "too long".to_string(),
+        }
+    );
+    let err = mint(&mut deps, &env, 10, None, Some("line\nbreak")).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidMemo {
+            reason: "only printable ASCII characters are allowed".to_string(),
+        }
+    );
+}
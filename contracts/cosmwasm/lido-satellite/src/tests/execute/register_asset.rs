@@ -0,0 +1,178 @@
+use crate::{
+    contract::execute,
+    execute::FEE_DENOM,
+    msg::ExecuteMsg,
+    tests::helpers::{
+        assert_asset, instantiate_wrapper, VALID_IBC_DENOM, VALID_IBC_DENOM_2, VALID_IBC_DENOM_3,
+    },
+    ContractError,
+};
+use cosmwasm_std::{attr, coin, testing::mock_info};
+use neutron_sdk::bindings::msg::NeutronMsg;
+
+#[test]
+fn stranger() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    let err = execute(
+        deps.as_mut(),
+        env,
+        mock_info("stranger", &[]),
+        ExecuteMsg::RegisterAsset {
+            canonical_subdenom: "steth".to_string(),
+            bridged_denoms: vec![VALID_IBC_DENOM_2.to_string()],
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::NotBridgeManager {});
+}
+
+#[test]
+fn unexpected_funds() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    let register = ExecuteMsg::RegisterAsset {
+        canonical_subdenom: "steth".to_string(),
+        bridged_denoms: vec![VALID_IBC_DENOM_2.to_string()],
+    };
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("admin", &[coin(10, VALID_IBC_DENOM)]),
+        register.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::UnexpectedFunds {
+            denom: VALID_IBC_DENOM.to_string(),
+        }
+    );
+
+    // fee funds pay for creation of tokenfactory denom
+    execute(
+        deps.as_mut(),
+        env,
+        mock_info("admin", &[coin(1_000_000, FEE_DENOM)]),
+        register,
+    )
+    .unwrap();
+}
+
+#[test]
+fn already_registered_subdenom() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    let err = execute(
+        deps.as_mut(),
+        env,
+        mock_info("admin", &[]),
+        ExecuteMsg::RegisterAsset {
+            canonical_subdenom: "eth".to_string(),
+            bridged_denoms: vec![VALID_IBC_DENOM_2.to_string()],
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::AssetAlreadyRegistered {
+            subdenom: "eth".to_string()
+        }
+    );
+}
+
+#[test]
+fn already_registered_bridged_denom() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    let err = execute(
+        deps.as_mut(),
+        env,
+        mock_info("admin", &[]),
+        ExecuteMsg::RegisterAsset {
+            canonical_subdenom: "steth".to_string(),
+            bridged_denoms: vec![VALID_IBC_DENOM_2.to_string(), VALID_IBC_DENOM.to_string()],
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::BridgedDenomAlreadyRegistered {
+            denom: VALID_IBC_DENOM.to_string()
+        }
+    );
+}
+
+#[test]
+fn no_bridged_denoms() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    let err = execute(
+        deps.as_mut(),
+        env,
+        mock_info("admin", &[]),
+        ExecuteMsg::RegisterAsset {
+            canonical_subdenom: "steth".to_string(),
+            bridged_denoms: vec![],
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::NoBridgedDenoms {});
+}
+
+#[test]
+fn invalid_bridged_denom() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    let err = execute(
+        deps.as_mut(),
+        env,
+        mock_info("admin", &[]),
+        ExecuteMsg::RegisterAsset {
+            canonical_subdenom: "steth".to_string(),
+            bridged_denoms: vec!["".to_string()],
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::EmptyDenom {
+            kind: "bridged_denoms".to_string()
+        }
+    );
+}
+
+#[test]
+fn success() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    let response = execute(
+        deps.as_mut(),
+        env,
+        mock_info("admin", &[]),
+        ExecuteMsg::RegisterAsset {
+            canonical_subdenom: "steth".to_string(),
+            bridged_denoms: vec![VALID_IBC_DENOM_2.to_string(), VALID_IBC_DENOM_3.to_string()],
+        },
+    )
+    .unwrap();
+    assert_eq!(response.messages.len(), 1);
+    assert_eq!(
+        response.messages[0].msg,
+        NeutronMsg::CreateDenom {
+            subdenom: "steth".to_string()
+        }
+        .into()
+    );
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "register_asset"),
+            attr("canonical_subdenom", "steth"),
+            attr(
+                "bridged_denoms",
+                format!("{VALID_IBC_DENOM_2},{VALID_IBC_DENOM_3}")
+            ),
+        ]
+    );
+    assert_asset(
+        deps.as_ref(),
+        "steth",
+        &[VALID_IBC_DENOM_2, VALID_IBC_DENOM_3],
+        "steth",
+    );
+    assert_asset(deps.as_ref(), "eth", &[VALID_IBC_DENOM], "eth");
+}
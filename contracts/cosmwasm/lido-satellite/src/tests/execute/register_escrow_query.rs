@@ -0,0 +1,181 @@
+use crate::{
+    contract::{execute, reply, sudo, REGISTER_ESCROW_QUERY_REPLY_ID},
+    msg::{ExecuteMsg, SudoMsg},
+    state::{EscrowQuery, ESCROW_QUERIES, ESCROW_QUERY_IDS},
+    tests::helpers::{instantiate_wrapper, MockDeps, VALID_IBC_DENOM, VALID_IBC_DENOM_2},
+    ContractError,
+};
+use cosmwasm_std::{
+    attr, coin, testing::mock_info, to_json_binary, Env, Reply, Response, SubMsg, SubMsgResponse,
+    SubMsgResult,
+};
+use neutron_sdk::bindings::msg::{MsgRegisterInterchainQueryResponse, NeutronMsg};
+
+const ESCROW_ADDRESS: &str = "axelar1qypqxpq9qcrsszg2pvxq6rs0zqg3yyc5mv6kda";
+
+fn register(
+    deps: &mut MockDeps,
+    env: &Env,
+    sender: &str,
+    bridged_denom: &str,
+) -> Response<NeutronMsg> {
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(sender, &[coin(1_000_000, "untrn")]),
+        ExecuteMsg::RegisterEscrowQuery {
+            bridged_denom: bridged_denom.to_string(),
+            connection_id: "connection-0".to_string(),
+            escrow_address: ESCROW_ADDRESS.to_string(),
+            remote_denom: "wei".to_string(),
+            update_period: 100,
+        },
+    )
+    .unwrap()
+}
+
+fn confirm(deps: &mut MockDeps, env: &Env, query_id: u64) {
+    reply(
+        deps.as_mut(),
+        env.clone(),
+        Reply {
+            id: REGISTER_ESCROW_QUERY_REPLY_ID,
+            result: SubMsgResult::Ok(SubMsgResponse {
+                events: vec![],
+                data: Some(
+                    to_json_binary(&MsgRegisterInterchainQueryResponse { id: query_id }).unwrap(),
+                ),
+            }),
+        },
+    )
+    .unwrap();
+}
+
+#[test]
+fn success() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    let response = register(&mut deps, &env, "admin", VALID_IBC_DENOM);
+    assert_eq!(response.messages.len(), 1);
+    assert_eq!(response.messages[0].id, REGISTER_ESCROW_QUERY_REPLY_ID);
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "register_escrow_query"),
+            attr("bridged_denom", VALID_IBC_DENOM),
+            attr("connection_id", "connection-0"),
+            attr("escrow_address", ESCROW_ADDRESS),
+            attr("remote_denom", "wei"),
+        ]
+    );
+
+    confirm(&mut deps, &env, 7);
+    assert_eq!(
+        ESCROW_QUERIES
+            .load(deps.as_ref().storage, VALID_IBC_DENOM)
+            .unwrap(),
+        EscrowQuery {
+            connection_id: "connection-0".to_string(),
+            escrow_address: ESCROW_ADDRESS.to_string(),
+            remote_denom: "wei".to_string(),
+            update_period: 100,
+            query_id: Some(7),
+        }
+    );
+    assert_eq!(
+        ESCROW_QUERY_IDS.load(deps.as_ref().storage, 7).unwrap(),
+        VALID_IBC_DENOM
+    );
+}
+
+#[test]
+fn replaces_previous_query() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    register(&mut deps, &env, "admin", VALID_IBC_DENOM);
+    confirm(&mut deps, &env, 7);
+
+    let response = register(&mut deps, &env, "admin", VALID_IBC_DENOM);
+    assert_eq!(
+        response.messages[0],
+        SubMsg::new(NeutronMsg::remove_interchain_query(7))
+    );
+    assert!(!ESCROW_QUERY_IDS.has(deps.as_ref().storage, 7));
+    confirm(&mut deps, &env, 8);
+    assert_eq!(
+        ESCROW_QUERY_IDS.load(deps.as_ref().storage, 8).unwrap(),
+        VALID_IBC_DENOM
+    );
+}
+
+#[test]
+fn unexpected_funds() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    let err = execute(
+        deps.as_mut(),
+        env,
+        mock_info("admin", &[coin(1_000_000, VALID_IBC_DENOM)]),
+        ExecuteMsg::RegisterEscrowQuery {
+            bridged_denom: VALID_IBC_DENOM.to_string(),
+            connection_id: "connection-0".to_string(),
+            escrow_address: ESCROW_ADDRESS.to_string(),
+            remote_denom: "wei".to_string(),
+            update_period: 100,
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::UnexpectedFunds {
+            denom: VALID_IBC_DENOM.to_string(),
+        }
+    );
+}
+
+#[test]
+fn by_stranger() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    let err = execute(
+        deps.as_mut(),
+        env,
+        mock_info("stranger", &[]),
+        ExecuteMsg::RegisterEscrowQuery {
+            bridged_denom: VALID_IBC_DENOM.to_string(),
+            connection_id: "connection-0".to_string(),
+            escrow_address: ESCROW_ADDRESS.to_string(),
+            remote_denom: "wei".to_string(),
+            update_period: 100,
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::NotBridgeManager {});
+}
+
+#[test]
+fn unregistered_bridged_denom() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    let err = execute(
+        deps.as_mut(),
+        env,
+        mock_info("admin", &[]),
+        ExecuteMsg::RegisterEscrowQuery {
+            bridged_denom: VALID_IBC_DENOM_2.to_string(),
+            connection_id: "connection-0".to_string(),
+            escrow_address: ESCROW_ADDRESS.to_string(),
+            remote_denom: "wei".to_string(),
+            update_period: 100,
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::BridgedDenomNotRegistered {
+            denom: VALID_IBC_DENOM_2.to_string(),
+        }
+    );
+}
+
+#[test]
+fn result_of_unknown_query() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    let err = sudo(deps.as_mut(), env, SudoMsg::KVQueryResult { query_id: 3 }).unwrap_err();
+    assert_eq!(err, ContractError::UnknownInterchainQuery { query_id: 3 });
+}
@@ -0,0 +1,189 @@
+use crate::{
+    contract::execute,
+    msg::ExecuteMsg,
+    state::{BridgeStatus, Role},
+    tests::helpers::{instantiate_wrapper, mint_msg, MockDeps, VALID_IBC_DENOM},
+    ContractError,
+};
+use cosmwasm_std::{attr, coin, testing::mock_info, Env};
+
+fn grant(deps: &mut MockDeps, env: &Env, role: Role, address: &str) {
+    let response = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("admin", &[]),
+        ExecuteMsg::GrantRole {
+            role,
+            address: address.to_string(),
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "grant_role"),
+            attr("role", role.to_string()),
+            attr("address", address),
+        ]
+    );
+}
+
+fn set_bridge_status(deps: &mut MockDeps, env: &Env, sender: &str) -> Result<(), ContractError> {
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(sender, &[]),
+        ExecuteMsg::SetBridgeStatus {
+            bridged_denom: VALID_IBC_DENOM.to_string(),
+            status: BridgeStatus::BurnOnly,
+        },
+    )
+    .map(|_| ())
+}
+
+fn pause(deps: &mut MockDeps, env: &Env, sender: &str) -> Result<(), ContractError> {
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(sender, &[]),
+        ExecuteMsg::Pause {},
+    )
+    .map(|_| ())
+}
+
+#[test]
+fn bridge_manager() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    grant(&mut deps, &env, Role::BridgeManager, "manager");
+    set_bridge_status(&mut deps, &env, "manager").unwrap();
+
+    // bridge manager can not act as a guardian or the owner
+    let err = pause(&mut deps, &env, "manager").unwrap_err();
+    assert_eq!(err, ContractError::NotGuardian {});
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("manager", &[]),
+        ExecuteMsg::GrantRole {
+            role: Role::BridgeManager,
+            address: "stranger".to_string(),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+}
+
+#[test]
+fn revoke() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    grant(&mut deps, &env, Role::BridgeManager, "manager");
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("admin", &[]),
+        ExecuteMsg::RevokeRole {
+            role: Role::BridgeManager,
+            address: "manager".to_string(),
+        },
+    )
+    .unwrap();
+    let err = set_bridge_status(&mut deps, &env, "manager").unwrap_err();
+    assert_eq!(err, ContractError::NotBridgeManager {});
+}
+
+#[test]
+fn grant_with_funds() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    let err = execute(
+        deps.as_mut(),
+        env,
+        mock_info("admin", &[coin(1, "untrn")]),
+        ExecuteMsg::GrantRole {
+            role: Role::Guardian,
+            address: "guardian".to_string(),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::UnexpectedFunds {
+            denom: "untrn".to_string(),
+        }
+    );
+}
+
+#[test]
+fn grant_by_stranger() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    let err = execute(
+        deps.as_mut(),
+        env,
+        mock_info("stranger", &[]),
+        ExecuteMsg::GrantRole {
+            role: Role::Guardian,
+            address: "stranger".to_string(),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+}
+
+#[test]
+fn guardian_pauses() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    grant(&mut deps, &env, Role::Guardian, "guardian");
+    pause(&mut deps, &env, "guardian").unwrap();
+
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("stranger", &[coin(10, VALID_IBC_DENOM)]),
+        mint_msg(None),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Paused {});
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("stranger", &[coin(10, "eth")]),
+        ExecuteMsg::Burn {
+            receiver: None,
+            bridged_denom: None,
+            referral: None,
+            memo: None,
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Paused {});
+
+    // only the owner can unpause
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("guardian", &[]),
+        ExecuteMsg::Unpause {},
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("admin", &[]),
+        ExecuteMsg::Unpause {},
+    )
+    .unwrap();
+    execute(
+        deps.as_mut(),
+        env,
+        mock_info("stranger", &[coin(10, VALID_IBC_DENOM)]),
+        mint_msg(None),
+    )
+    .unwrap();
+}
+
+#[test]
+fn pause_by_stranger() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    let err = pause(&mut deps, &env, "stranger").unwrap_err();
+    assert_eq!(err, ContractError::NotGuardian {});
+}
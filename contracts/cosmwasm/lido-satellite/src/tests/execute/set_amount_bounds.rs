@@ -0,0 +1,174 @@
+use crate::{
+    contract::execute,
+    msg::ExecuteMsg,
+    state::BRIDGES,
+    tests::helpers::{instantiate_wrapper, mint_msg, MockDeps, VALID_IBC_DENOM, VALID_IBC_DENOM_2},
+    ContractError,
+};
+use cosmwasm_std::{attr, coin, testing::mock_info, Env, Response, Uint128};
+use neutron_sdk::bindings::msg::NeutronMsg;
+
+fn set_bounds(
+    deps: &mut MockDeps,
+    env: &Env,
+    sender: &str,
+    min_amount: Option<u128>,
+    max_amount: Option<u128>,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(sender, &[]),
+        ExecuteMsg::SetAmountBounds {
+            bridged_denom: VALID_IBC_DENOM.to_string(),
+            min_amount: min_amount.map(Uint128::new),
+            max_amount: max_amount.map(Uint128::new),
+        },
+    )
+}
+
+fn mint(deps: &mut MockDeps, env: &Env, amount: u128) -> Result<(), ContractError> {
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("stranger", &[coin(amount, VALID_IBC_DENOM)]),
+        mint_msg(None),
+    )
+    .map(|_| ())
+}
+
+fn burn(deps: &mut MockDeps, env: &Env, amount: u128) -> Result<(), ContractError> {
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("stranger", &[coin(amount, "eth")]),
+        ExecuteMsg::Burn {
+            receiver: None,
+            bridged_denom: None,
+            referral: None,
+            memo: None,
+        },
+    )
+    .map(|_| ())
+}
+
+#[test]
+fn stranger() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    let err = set_bounds(&mut deps, &env, "stranger", Some(1), None).unwrap_err();
+    assert_eq!(err, ContractError::NotBridgeManager {});
+}
+
+#[test]
+fn unknown_bridged_denom() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    let err = execute(
+        deps.as_mut(),
+        env,
+        mock_info("admin", &[]),
+        ExecuteMsg::SetAmountBounds {
+            bridged_denom: VALID_IBC_DENOM_2.to_string(),
+            min_amount: None,
+            max_amount: None,
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::BridgedDenomNotRegistered {
+            denom: VALID_IBC_DENOM_2.to_string(),
+        }
+    );
+}
+
+#[test]
+fn min_above_max() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    let err = set_bounds(&mut deps, &env, "admin", Some(10), Some(5)).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidAmountBounds {
+            min_amount: Uint128::new(10),
+            max_amount: Uint128::new(5),
+        }
+    );
+}
+
+#[test]
+fn bounds_mint_and_burn() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    let response = set_bounds(&mut deps, &env, "admin", Some(10), Some(100)).unwrap();
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "set_amount_bounds"),
+            attr("bridged_denom", VALID_IBC_DENOM),
+            attr("min_amount", "10"),
+            attr("max_amount", "100"),
+        ]
+    );
+    let bridge = BRIDGES.load(&deps.storage, VALID_IBC_DENOM).unwrap();
+    assert_eq!(bridge.min_amount, Some(Uint128::new(10)));
+    assert_eq!(bridge.max_amount, Some(Uint128::new(100)));
+
+    let below_minimum = ContractError::AmountBelowMinimum {
+        denom: VALID_IBC_DENOM.to_string(),
+        amount: Uint128::new(9),
+        min_amount: Uint128::new(10),
+    };
+    let above_maximum = ContractError::AmountAboveMaximum {
+        denom: VALID_IBC_DENOM.to_string(),
+        amount: Uint128::new(101),
+        max_amount: Uint128::new(100),
+    };
+    assert_eq!(mint(&mut deps, &env, 9).unwrap_err(), below_minimum);
+    assert_eq!(mint(&mut deps, &env, 101).unwrap_err(), above_maximum);
+    mint(&mut deps, &env, 10).unwrap();
+    mint(&mut deps, &env, 100).unwrap();
+
+    deps.querier
+        .base
+        .update_balance(&env.contract.address, vec![coin(1000, VALID_IBC_DENOM)]);
+    assert_eq!(burn(&mut deps, &env, 9).unwrap_err(), below_minimum);
+    assert_eq!(burn(&mut deps, &env, 101).unwrap_err(), above_maximum);
+    burn(&mut deps, &env, 100).unwrap();
+
+    // removing bounds lifts restrictions
+    set_bounds(&mut deps, &env, "admin", None, None).unwrap();
+    mint(&mut deps, &env, 1).unwrap();
+    burn(&mut deps, &env, 1000).unwrap();
+}
+
+#[test]
+fn split_burn_bounds_shares() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("admin", &[]),
+        ExecuteMsg::AddBridgedDenom {
+            canonical_subdenom: "eth".to_string(),
+            bridged_denom: VALID_IBC_DENOM_2.to_string(),
+        },
+    )
+    .unwrap();
+    deps.querier.base.update_balance(
+        &env.contract.address,
+        vec![coin(60, VALID_IBC_DENOM), coin(40, VALID_IBC_DENOM_2)],
+    );
+
+    // 60 of 80 is paid out by the first bridge
+    set_bounds(&mut deps, &env, "admin", None, Some(50)).unwrap();
+    assert_eq!(
+        burn(&mut deps, &env, 80).unwrap_err(),
+        ContractError::AmountAboveMaximum {
+            denom: VALID_IBC_DENOM.to_string(),
+            amount: Uint128::new(60),
+            max_amount: Uint128::new(50),
+        }
+    );
+
+    // whole burned amount is above maximum, but the share of the bridge isn't
+    set_bounds(&mut deps, &env, "admin", None, Some(70)).unwrap();
+    burn(&mut deps, &env, 80).unwrap();
+}
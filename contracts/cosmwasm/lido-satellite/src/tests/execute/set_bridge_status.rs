@@ -0,0 +1,250 @@
+use crate::{
+    contract::{execute, query},
+    msg::{AssetResponse, BridgeResponse, ExecuteMsg, QueryMsg},
+    state::BridgeStatus,
+    tests::helpers::{
+        instantiate_wrapper, mint_msg, register_asset_wrapper, MockDeps, VALID_IBC_DENOM,
+        VALID_IBC_DENOM_2, VALID_IBC_DENOM_3,
+    },
+    ContractError,
+};
+use cosmwasm_std::{attr, coin, from_json, testing::mock_info, BankMsg, Env, Uint128};
+
+fn set_status(deps: &mut MockDeps, env: Env, bridged_denom: &str, status: BridgeStatus) {
+    execute(
+        deps.as_mut(),
+        env,
+        mock_info("admin", &[]),
+        ExecuteMsg::SetBridgeStatus {
+            bridged_denom: bridged_denom.to_string(),
+            status,
+        },
+    )
+    .unwrap();
+}
+
+#[test]
+fn stranger() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    let err = execute(
+        deps.as_mut(),
+        env,
+        mock_info("stranger", &[]),
+        ExecuteMsg::SetBridgeStatus {
+            bridged_denom: VALID_IBC_DENOM.to_string(),
+            status: BridgeStatus::BurnOnly,
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::NotBridgeManager {});
+}
+
+#[test]
+fn unknown_bridged_denom() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    let err = execute(
+        deps.as_mut(),
+        env,
+        mock_info("admin", &[]),
+        ExecuteMsg::SetBridgeStatus {
+            bridged_denom: VALID_IBC_DENOM_2.to_string(),
+            status: BridgeStatus::BurnOnly,
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::BridgedDenomNotRegistered {
+            denom: VALID_IBC_DENOM_2.to_string()
+        }
+    );
+}
+
+#[test]
+fn success() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    let response = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("admin", &[]),
+        ExecuteMsg::SetBridgeStatus {
+            bridged_denom: VALID_IBC_DENOM.to_string(),
+            status: BridgeStatus::BurnOnly,
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "set_bridge_status"),
+            attr("bridged_denom", VALID_IBC_DENOM),
+            attr("old_status", "active"),
+            attr("new_status", "burn_only"),
+        ]
+    );
+    let asset_response: AssetResponse = from_json(
+        query(
+            deps.as_ref(),
+            env,
+            QueryMsg::Asset {
+                canonical_subdenom: "eth".to_string(),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        asset_response.bridges,
+        vec![BridgeResponse {
+            bridged_denom: VALID_IBC_DENOM.to_string(),
+            status: BridgeStatus::BurnOnly,
+            min_amount: None,
+            max_amount: None,
+        }]
+    );
+}
+
+#[test]
+fn burn_only_bridge_rejects_mint() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    set_status(
+        &mut deps,
+        env.clone(),
+        VALID_IBC_DENOM,
+        BridgeStatus::BurnOnly,
+    );
+    let err = execute(
+        deps.as_mut(),
+        env,
+        mock_info("stranger", &[coin(10, VALID_IBC_DENOM)]),
+        mint_msg(None),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::MintNotAllowed {
+            denom: VALID_IBC_DENOM.to_string(),
+            status: BridgeStatus::BurnOnly,
+        }
+    );
+}
+
+#[test]
+fn burn_only_bridge_is_drained_by_burn() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    set_status(
+        &mut deps,
+        env.clone(),
+        VALID_IBC_DENOM,
+        BridgeStatus::BurnOnly,
+    );
+    deps.querier
+        .base
+        .update_balance(&env.contract.address, vec![coin(100, VALID_IBC_DENOM)]);
+    let response = execute(
+        deps.as_mut(),
+        env,
+        mock_info("stranger", &[coin(10, "eth")]),
+        ExecuteMsg::Burn {
+            receiver: None,
+            bridged_denom: None,
+            referral: None,
+            memo: None,
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        response.messages[1].msg,
+        BankMsg::Send {
+            to_address: "stranger".to_string(),
+            amount: vec![coin(10, VALID_IBC_DENOM)],
+        }
+        .into()
+    );
+}
+
+#[test]
+fn disabled_bridge_rejects_mint() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    set_status(
+        &mut deps,
+        env.clone(),
+        VALID_IBC_DENOM,
+        BridgeStatus::Disabled,
+    );
+    let err = execute(
+        deps.as_mut(),
+        env,
+        mock_info("stranger", &[coin(10, VALID_IBC_DENOM)]),
+        mint_msg(None),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::MintNotAllowed {
+            denom: VALID_IBC_DENOM.to_string(),
+            status: BridgeStatus::Disabled,
+        }
+    );
+}
+
+#[test]
+fn disabled_bridge_is_skipped_by_burn() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    register_asset_wrapper(
+        deps.as_mut(),
+        env.clone(),
+        "steth",
+        &[VALID_IBC_DENOM_2, VALID_IBC_DENOM_3],
+    );
+    set_status(
+        &mut deps,
+        env.clone(),
+        VALID_IBC_DENOM_2,
+        BridgeStatus::Disabled,
+    );
+    deps.querier.base.update_balance(
+        &env.contract.address,
+        vec![coin(100, VALID_IBC_DENOM_2), coin(10, VALID_IBC_DENOM_3)],
+    );
+
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("stranger", &[coin(15, "steth")]),
+        ExecuteMsg::Burn {
+            receiver: None,
+            bridged_denom: None,
+            referral: None,
+            memo: None,
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InsufficientReserves {
+            denom: VALID_IBC_DENOM_3.to_string(),
+            available: Uint128::new(10),
+            requested: Uint128::new(15),
+        }
+    );
+
+    let err = execute(
+        deps.as_mut(),
+        env,
+        mock_info("stranger", &[coin(15, "steth")]),
+        ExecuteMsg::Burn {
+            receiver: None,
+            bridged_denom: Some(VALID_IBC_DENOM_2.to_string()),
+            referral: None,
+            memo: None,
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::BridgeDisabled {
+            denom: VALID_IBC_DENOM_2.to_string(),
+        }
+    );
+}
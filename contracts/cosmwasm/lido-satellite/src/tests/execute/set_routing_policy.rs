@@ -0,0 +1,70 @@
+use crate::{
+    contract::execute,
+    msg::ExecuteMsg,
+    state::{RoutingPolicy, ASSETS},
+    tests::helpers::{instantiate_wrapper, VALID_IBC_DENOM},
+    ContractError,
+};
+use cosmwasm_std::{attr, testing::mock_info};
+
+#[test]
+fn stranger() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    let err = execute(
+        deps.as_mut(),
+        env,
+        mock_info("stranger", &[]),
+        ExecuteMsg::SetRoutingPolicy {
+            canonical_subdenom: "eth".to_string(),
+            routing_policy: RoutingPolicy::LargestReserve,
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::NotBridgeManager {});
+}
+
+#[test]
+fn unknown_asset() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    let err = execute(
+        deps.as_mut(),
+        env,
+        mock_info("admin", &[]),
+        ExecuteMsg::SetRoutingPolicy {
+            canonical_subdenom: "steth".to_string(),
+            routing_policy: RoutingPolicy::LargestReserve,
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::AssetNotRegistered {
+            subdenom: "steth".to_string()
+        }
+    );
+}
+
+#[test]
+fn success() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    let response = execute(
+        deps.as_mut(),
+        env,
+        mock_info("admin", &[]),
+        ExecuteMsg::SetRoutingPolicy {
+            canonical_subdenom: "eth".to_string(),
+            routing_policy: RoutingPolicy::LargestReserve,
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "set_routing_policy"),
+            attr("canonical_subdenom", "eth"),
+            attr("routing_policy", "largest_reserve"),
+        ]
+    );
+    let asset = ASSETS.load(deps.as_ref().storage, "eth").unwrap();
+    assert_eq!(asset.routing_policy, RoutingPolicy::LargestReserve);
+}
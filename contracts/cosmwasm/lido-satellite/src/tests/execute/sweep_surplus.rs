@@ -0,0 +1,213 @@
+use crate::{
+    contract::{execute, query},
+    execute::FEE_DENOM,
+    msg::{ExecuteMsg, QueryMsg, SurplusResponse},
+    state::Role,
+    tests::helpers::{instantiate_wrapper, mint_msg, MockDeps, VALID_IBC_DENOM},
+    ContractError,
+};
+use cosmwasm_std::{attr, coin, from_json, testing::mock_info, BankMsg, Env, Response, Uint128};
+use neutron_sdk::bindings::msg::NeutronMsg;
+
+fn mint(deps: &mut MockDeps, env: &Env, amount: u128) {
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("stranger", &[coin(amount, VALID_IBC_DENOM)]),
+        mint_msg(None),
+    )
+    .unwrap();
+}
+
+fn sweep(
+    deps: &mut MockDeps,
+    env: &Env,
+    sender: &str,
+    denom: &str,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(sender, &[]),
+        ExecuteMsg::SweepSurplus {
+            denom: denom.to_string(),
+            to: "treasury".to_string(),
+        },
+    )
+}
+
+#[test]
+fn reports_surplus() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    mint(&mut deps, &env, 100);
+    deps.querier.base.update_balance(
+        &env.contract.address,
+        vec![coin(130, VALID_IBC_DENOM), coin(5, "ldo")],
+    );
+
+    let response: Vec<SurplusResponse> =
+        from_json(query(deps.as_ref(), env, QueryMsg::Surplus {}).unwrap()).unwrap();
+    assert_eq!(
+        response,
+        vec![
+            SurplusResponse {
+                denom: VALID_IBC_DENOM.to_string(),
+                balance: Uint128::new(130),
+                expected: Uint128::new(100),
+                surplus: Uint128::new(30),
+            },
+            SurplusResponse {
+                denom: "ldo".to_string(),
+                balance: Uint128::new(5),
+                expected: Uint128::zero(),
+                surplus: Uint128::new(5),
+            },
+        ]
+    );
+}
+
+#[test]
+fn sweeps_surplus() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    mint(&mut deps, &env, 100);
+    deps.querier
+        .base
+        .update_balance(&env.contract.address, vec![coin(130, VALID_IBC_DENOM)]);
+
+    let response = sweep(&mut deps, &env, "admin", VALID_IBC_DENOM).unwrap();
+    assert_eq!(
+        response.messages[0].msg,
+        BankMsg::Send {
+            to_address: "treasury".to_string(),
+            amount: vec![coin(30, VALID_IBC_DENOM)],
+        }
+        .into()
+    );
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "sweep_surplus"),
+            attr("denom", VALID_IBC_DENOM),
+            attr("amount", "30"),
+            attr("to", "treasury"),
+        ]
+    );
+}
+
+#[test]
+fn no_surplus() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    mint(&mut deps, &env, 100);
+    deps.querier
+        .base
+        .update_balance(&env.contract.address, vec![coin(100, VALID_IBC_DENOM)]);
+
+    let err = sweep(&mut deps, &env, "admin", VALID_IBC_DENOM).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::NoSurplus {
+            denom: VALID_IBC_DENOM.to_string(),
+        }
+    );
+}
+
+#[test]
+fn never_sweeps_backing_reserves() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    // reserves received before expected balances were tracked
+    deps.querier
+        .base
+        .update_balance(&env.contract.address, vec![coin(100, VALID_IBC_DENOM)]);
+    deps.querier
+        .base
+        .update_balance("holder", vec![coin(80, "eth")]);
+
+    let response = sweep(&mut deps, &env, "admin", VALID_IBC_DENOM).unwrap();
+    assert_eq!(
+        response.messages[0].msg,
+        BankMsg::Send {
+            to_address: "treasury".to_string(),
+            amount: vec![coin(20, VALID_IBC_DENOM)],
+        }
+        .into()
+    );
+}
+
+#[test]
+fn burns_reduce_expected_balance() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    mint(&mut deps, &env, 100);
+    deps.querier
+        .base
+        .update_balance(&env.contract.address, vec![coin(100, VALID_IBC_DENOM)]);
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("stranger", &[coin(40, "eth")]),
+        ExecuteMsg::Burn {
+            receiver: None,
+            bridged_denom: None,
+            referral: None,
+            memo: None,
+        },
+    )
+    .unwrap();
+    deps.querier
+        .base
+        .update_balance(&env.contract.address, vec![coin(65, VALID_IBC_DENOM)]);
+
+    let response = sweep(&mut deps, &env, "admin", VALID_IBC_DENOM).unwrap();
+    assert_eq!(
+        response.messages[0].msg,
+        BankMsg::Send {
+            to_address: "treasury".to_string(),
+            amount: vec![coin(5, VALID_IBC_DENOM)],
+        }
+        .into()
+    );
+}
+
+#[test]
+fn unauthorized() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    deps.querier
+        .base
+        .update_balance(&env.contract.address, vec![coin(5, "ldo")]);
+    let err = sweep(&mut deps, &env, "stranger", "ldo").unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+}
+
+#[test]
+fn fee_manager_sweeps_fees() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    deps.querier.base.update_balance(
+        &env.contract.address,
+        vec![coin(5, "ldo"), coin(7, FEE_DENOM)],
+    );
+    let err = sweep(&mut deps, &env, "fee_manager", FEE_DENOM).unwrap_err();
+    assert_eq!(err, ContractError::NotFeeManager {});
+
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("admin", &[]),
+        ExecuteMsg::GrantRole {
+            role: Role::FeeManager,
+            address: "fee_manager".to_string(),
+        },
+    )
+    .unwrap();
+    let response = sweep(&mut deps, &env, "fee_manager", FEE_DENOM).unwrap();
+    assert_eq!(
+        response.messages[0].msg,
+        BankMsg::Send {
+            to_address: "treasury".to_string(),
+            amount: vec![coin(7, FEE_DENOM)],
+        }
+        .into()
+    );
+
+    // other surplus is still swept by the owner only
+    let err = sweep(&mut deps, &env, "fee_manager", "ldo").unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+}
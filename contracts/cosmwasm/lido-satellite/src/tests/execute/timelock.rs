@@ -0,0 +1,318 @@
+use crate::{
+    contract::execute,
+    msg::ExecuteMsg,
+    state::{
+        BridgeStatus, ConfigChange, Role, RoutingPolicy, ASSETS, BRIDGES, QUEUED_CHANGES,
+        TIMELOCK_DELAY,
+    },
+    tests::helpers::{instantiate_wrapper, MockDeps, VALID_IBC_DENOM, VALID_IBC_DENOM_2},
+    ContractError,
+};
+use cosmwasm_std::{attr, testing::mock_info, Env, Response};
+use neutron_sdk::bindings::msg::NeutronMsg;
+
+const DELAY: u64 = 24 * 60 * 60;
+
+fn exec(
+    deps: &mut MockDeps,
+    env: &Env,
+    sender: &str,
+    msg: ExecuteMsg,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    execute(deps.as_mut(), env.clone(), mock_info(sender, &[]), msg)
+}
+
+fn disable_bridge() -> ConfigChange {
+    ConfigChange::SetBridgeStatus {
+        bridged_denom: VALID_IBC_DENOM.to_string(),
+        status: BridgeStatus::Disabled,
+    }
+}
+
+/// Sets timelock delay through the queue, which takes effect right away while delay is zero
+fn set_delay(deps: &mut MockDeps, env: &Env) {
+    exec(
+        deps,
+        env,
+        "admin",
+        ExecuteMsg::QueueChange {
+            change: ConfigChange::SetTimelockDelay { delay: DELAY },
+        },
+    )
+    .unwrap();
+    let response = exec(deps, env, "admin", ExecuteMsg::ExecuteQueued { id: 0 }).unwrap();
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "set_timelock_delay"),
+            attr("delay", DELAY.to_string()),
+            attr("queued_change_id", "0"),
+        ]
+    );
+    assert_eq!(TIMELOCK_DELAY.load(&deps.storage).unwrap(), DELAY);
+}
+
+#[test]
+fn direct_change_rejected_while_timelocked() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    set_delay(&mut deps, &env);
+    let err = exec(
+        &mut deps,
+        &env,
+        "admin",
+        ExecuteMsg::SetBridgeStatus {
+            bridged_denom: VALID_IBC_DENOM.to_string(),
+            status: BridgeStatus::Disabled,
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::TimelockActive {
+            change: "set_bridge_status".to_string(),
+        }
+    );
+}
+
+#[test]
+fn asset_registry_changes_rejected_while_timelocked() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    set_delay(&mut deps, &env);
+    let err = exec(
+        &mut deps,
+        &env,
+        "admin",
+        ExecuteMsg::RegisterAsset {
+            canonical_subdenom: "steth".to_string(),
+            bridged_denoms: vec![VALID_IBC_DENOM_2.to_string()],
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::TimelockActive {
+            change: "register_asset".to_string(),
+        }
+    );
+    assert!(!ASSETS.has(&deps.storage, "steth"));
+    assert!(!BRIDGES.has(&deps.storage, VALID_IBC_DENOM_2));
+
+    let err = exec(
+        &mut deps,
+        &env,
+        "admin",
+        ExecuteMsg::SetRoutingPolicy {
+            canonical_subdenom: "eth".to_string(),
+            routing_policy: RoutingPolicy::LargestReserve,
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::TimelockActive {
+            change: "set_routing_policy".to_string(),
+        }
+    );
+}
+
+#[test]
+fn register_asset_after_delay() {
+    let (_result, mut deps, mut env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    set_delay(&mut deps, &env);
+    exec(
+        &mut deps,
+        &env,
+        "admin",
+        ExecuteMsg::QueueChange {
+            change: ConfigChange::RegisterAsset {
+                canonical_subdenom: "steth".to_string(),
+                bridged_denoms: vec![VALID_IBC_DENOM_2.to_string()],
+            },
+        },
+    )
+    .unwrap();
+
+    env.block.time = env.block.time.plus_seconds(DELAY);
+    let response = exec(
+        &mut deps,
+        &env,
+        "admin",
+        ExecuteMsg::ExecuteQueued { id: 1 },
+    )
+    .unwrap();
+    // tokenfactory denom is created the same way as with a direct registration
+    assert_eq!(response.messages.len(), 1);
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "register_asset"),
+            attr("canonical_subdenom", "steth"),
+            attr("bridged_denoms", VALID_IBC_DENOM_2),
+            attr("queued_change_id", "1"),
+        ]
+    );
+    assert_eq!(
+        BRIDGES
+            .load(&deps.storage, VALID_IBC_DENOM_2)
+            .unwrap()
+            .canonical_subdenom,
+        "steth"
+    );
+}
+
+#[test]
+fn execute_after_delay() {
+    let (_result, mut deps, mut env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    set_delay(&mut deps, &env);
+    let eta = env.block.time.plus_seconds(DELAY);
+    let response = exec(
+        &mut deps,
+        &env,
+        "admin",
+        ExecuteMsg::QueueChange {
+            change: disable_bridge(),
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "queue_change"),
+            attr("id", "1"),
+            attr("change", "set_bridge_status"),
+            attr("proposer", "admin"),
+            attr("eta", eta.to_string()),
+        ]
+    );
+
+    env.block.time = eta.minus_seconds(1);
+    let err = exec(
+        &mut deps,
+        &env,
+        "admin",
+        ExecuteMsg::ExecuteQueued { id: 1 },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::QueuedChangeNotReady { id: 1, eta });
+
+    env.block.time = eta;
+    exec(
+        &mut deps,
+        &env,
+        "admin",
+        ExecuteMsg::ExecuteQueued { id: 1 },
+    )
+    .unwrap();
+    let bridge = BRIDGES.load(&deps.storage, VALID_IBC_DENOM).unwrap();
+    assert_eq!(bridge.status, BridgeStatus::Disabled);
+    assert!(!QUEUED_CHANGES.has(&deps.storage, 1));
+
+    // a change can only be executed once
+    let err = exec(
+        &mut deps,
+        &env,
+        "admin",
+        ExecuteMsg::ExecuteQueued { id: 1 },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::QueuedChangeNotFound { id: 1 });
+}
+
+#[test]
+fn queue_requires_authority_over_change() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    exec(
+        &mut deps,
+        &env,
+        "admin",
+        ExecuteMsg::GrantRole {
+            role: Role::BridgeManager,
+            address: "manager".to_string(),
+        },
+    )
+    .unwrap();
+
+    exec(
+        &mut deps,
+        &env,
+        "manager",
+        ExecuteMsg::QueueChange {
+            change: disable_bridge(),
+        },
+    )
+    .unwrap();
+    let err = exec(
+        &mut deps,
+        &env,
+        "manager",
+        ExecuteMsg::QueueChange {
+            change: ConfigChange::SetTimelockDelay { delay: 0 },
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+    let err = exec(
+        &mut deps,
+        &env,
+        "stranger",
+        ExecuteMsg::ExecuteQueued { id: 0 },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::NotBridgeManager {});
+}
+
+#[test]
+fn guardian_cancels() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    exec(
+        &mut deps,
+        &env,
+        "admin",
+        ExecuteMsg::GrantRole {
+            role: Role::Guardian,
+            address: "guardian".to_string(),
+        },
+    )
+    .unwrap();
+    exec(
+        &mut deps,
+        &env,
+        "admin",
+        ExecuteMsg::QueueChange {
+            change: disable_bridge(),
+        },
+    )
+    .unwrap();
+
+    let err = exec(
+        &mut deps,
+        &env,
+        "stranger",
+        ExecuteMsg::CancelQueued { id: 0 },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::NotGuardian {});
+    let response = exec(
+        &mut deps,
+        &env,
+        "guardian",
+        ExecuteMsg::CancelQueued { id: 0 },
+    )
+    .unwrap();
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "cancel_queued"),
+            attr("id", "0"),
+            attr("change", "set_bridge_status"),
+        ]
+    );
+    let err = exec(
+        &mut deps,
+        &env,
+        "admin",
+        ExecuteMsg::ExecuteQueued { id: 0 },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::QueuedChangeNotFound { id: 0 });
+}
@@ -0,0 +1,275 @@
+use crate::{
+    contract::{execute, query},
+    msg::{ExecuteMsg, QueryMsg, WithdrawalResponse},
+    state::{Role, WithdrawalQueueConfig, QUEUED_PAYOUTS},
+    tests::helpers::{instantiate_wrapper, MockDeps, VALID_IBC_DENOM},
+    ContractError,
+};
+use cosmwasm_std::{
+    attr, coin, from_json, testing::mock_info, Addr, BankMsg, CosmosMsg, Env, Response, Uint128,
+};
+use neutron_sdk::bindings::msg::NeutronMsg;
+
+const DELAY: u64 = 60 * 60;
+
+fn exec(
+    deps: &mut MockDeps,
+    env: &Env,
+    sender: &str,
+    funds: &[cosmwasm_std::Coin],
+    msg: ExecuteMsg,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    execute(deps.as_mut(), env.clone(), mock_info(sender, funds), msg)
+}
+
+/// Instantiates contract with a withdrawal queue for burns of more than 100 and
+/// a reserve of 1000
+fn setup() -> (MockDeps, Env) {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    exec(
+        &mut deps,
+        &env,
+        "admin",
+        &[],
+        ExecuteMsg::SetWithdrawalQueue {
+            canonical_subdenom: "eth".to_string(),
+            config: Some(WithdrawalQueueConfig {
+                threshold: Uint128::new(100),
+                delay: DELAY,
+            }),
+        },
+    )
+    .unwrap();
+    exec(
+        &mut deps,
+        &env,
+        "admin",
+        &[],
+        ExecuteMsg::GrantRole {
+            role: Role::Guardian,
+            address: "guardian".to_string(),
+        },
+    )
+    .unwrap();
+    deps.querier
+        .base
+        .update_balance(&env.contract.address, vec![coin(1000, VALID_IBC_DENOM)]);
+    (deps, env)
+}
+
+fn burn(
+    deps: &mut MockDeps,
+    env: &Env,
+    amount: u128,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    exec(
+        deps,
+        env,
+        "stranger",
+        &[coin(amount, "eth")],
+        ExecuteMsg::Burn {
+            receiver: None,
+            bridged_denom: None,
+            referral: None,
+            memo: None,
+        },
+    )
+}
+
+fn payout_msg(amount: u128) -> CosmosMsg<NeutronMsg> {
+    BankMsg::Send {
+        to_address: "stranger".to_string(),
+        amount: vec![coin(amount, VALID_IBC_DENOM)],
+    }
+    .into()
+}
+
+#[test]
+fn small_burn_is_paid_out_instantly() {
+    let (mut deps, env) = setup();
+    let response = burn(&mut deps, &env, 100).unwrap();
+    assert_eq!(response.messages[1].msg, payout_msg(100));
+}
+
+#[test]
+fn large_burn_is_queued() {
+    let (mut deps, mut env) = setup();
+    let release_at = env.block.time.plus_seconds(DELAY);
+    let response = burn(&mut deps, &env, 600).unwrap();
+    assert_eq!(response.messages.len(), 1);
+    assert_eq!(
+        response.attributes[4..],
+        [
+            attr("withdrawal_id", "0"),
+            attr("release_at", release_at.to_string()),
+        ]
+    );
+
+    let withdrawals: Vec<WithdrawalResponse> = from_json(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::Withdrawals {
+                receiver: "stranger".to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        withdrawals,
+        vec![WithdrawalResponse {
+            id: 0,
+            sender: "stranger".to_string(),
+            receiver: "stranger".to_string(),
+            canonical_denom: "eth".to_string(),
+            amount: Uint128::new(600),
+            payout: vec![coin(600, VALID_IBC_DENOM)],
+            release_at,
+            evm_transfer: None,
+        }]
+    );
+
+    // queued payout can not be spent by other burns
+    let err = burn(&mut deps, &env, 500).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InsufficientReserves {
+            denom: VALID_IBC_DENOM.to_string(),
+            available: Uint128::new(400),
+            requested: Uint128::new(500),
+        }
+    );
+
+    let err = exec(
+        &mut deps,
+        &env,
+        "anyone",
+        &[],
+        ExecuteMsg::ClaimWithdrawal { id: 0 },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::WithdrawalNotReady { id: 0, release_at });
+
+    env.block.time = release_at;
+    let response = exec(
+        &mut deps,
+        &env,
+        "anyone",
+        &[],
+        ExecuteMsg::ClaimWithdrawal { id: 0 },
+    )
+    .unwrap();
+    assert_eq!(response.messages[0].msg, payout_msg(600));
+    assert_eq!(
+        QUEUED_PAYOUTS.load(&deps.storage, VALID_IBC_DENOM).unwrap(),
+        Uint128::zero()
+    );
+    let err = exec(
+        &mut deps,
+        &env,
+        "anyone",
+        &[],
+        ExecuteMsg::ClaimWithdrawal { id: 0 },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::WithdrawalNotFound { id: 0 });
+}
+
+#[test]
+fn guardian_approves() {
+    let (mut deps, env) = setup();
+    burn(&mut deps, &env, 600).unwrap();
+    let err = exec(
+        &mut deps,
+        &env,
+        "stranger",
+        &[],
+        ExecuteMsg::ApproveWithdrawal { id: 0 },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::NotGuardian {});
+    let response = exec(
+        &mut deps,
+        &env,
+        "guardian",
+        &[],
+        ExecuteMsg::ApproveWithdrawal { id: 0 },
+    )
+    .unwrap();
+    assert_eq!(response.messages[0].msg, payout_msg(600));
+}
+
+#[test]
+fn guardian_cancels() {
+    let (mut deps, env) = setup();
+    burn(&mut deps, &env, 600).unwrap();
+    let response = exec(
+        &mut deps,
+        &env,
+        "guardian",
+        &[],
+        ExecuteMsg::CancelWithdrawal { id: 0 },
+    )
+    .unwrap();
+    assert_eq!(
+        response.messages[0].msg,
+        NeutronMsg::submit_mint_tokens("eth", Uint128::new(600), Addr::unchecked("stranger"))
+            .into()
+    );
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "cancel_withdrawal"),
+            attr("id", "0"),
+            attr("amount", "600"),
+            attr("sender", "stranger"),
+        ]
+    );
+    // the whole reserve is available again
+    burn(&mut deps, &env, 1000).unwrap();
+}
+
+#[test]
+fn migration_waits_for_queue() {
+    let (mut deps, env) = setup();
+    burn(&mut deps, &env, 600).unwrap();
+    let migrate = ExecuteMsg::MigrateToSuccessor {
+        successor: "successor".to_string(),
+    };
+    let err = exec(&mut deps, &env, "admin", &[], migrate.clone()).unwrap_err();
+    assert_eq!(err, ContractError::WithdrawalsQueued {});
+
+    exec(
+        &mut deps,
+        &env,
+        "guardian",
+        &[],
+        ExecuteMsg::ApproveWithdrawal { id: 0 },
+    )
+    .unwrap();
+    exec(&mut deps, &env, "admin", &[], migrate).unwrap();
+}
+
+#[test]
+fn claim_with_funds() {
+    let (mut deps, mut env) = setup();
+    burn(&mut deps, &env, 600).unwrap();
+    env.block.time = env.block.time.plus_seconds(DELAY);
+    let err = exec(
+        &mut deps,
+        &env,
+        "anyone",
+        &[coin(1, "eth")],
+        ExecuteMsg::ClaimWithdrawal { id: 0 },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::UnexpectedFunds {
+            denom: "eth".to_string(),
+        }
+    );
+}
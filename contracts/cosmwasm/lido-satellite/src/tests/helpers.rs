@@ -1,34 +1,30 @@
 use crate::{
-    contract::instantiate,
-    msg::InstantiateMsg,
-    state::{Config, CONFIG},
+    contract::{execute, instantiate},
+    msg::{ExecuteMsg, InstantiateMsg},
+    state::{Asset, Config, RoutingPolicy, ASSETS, CONFIG},
+    testing::{mock_neutron_dependencies, NeutronMockDeps},
     ContractResult,
 };
 use cosmwasm_std::{
-    testing::{mock_env, mock_info, MockApi, MockQuerier, MockStorage},
-    Deps, Env, OwnedDeps, Response,
+    testing::{mock_env, mock_info},
+    Addr, Deps, DepsMut, Env, Response,
 };
 use neutron_sdk::bindings::{msg::NeutronMsg, query::NeutronQuery};
-use std::marker::PhantomData;
 
 pub const VALID_IBC_DENOM: &str =
     "ibc/584A4A23736884E0C198FD1EE932455A9357A492A7B94324E4A02B5628687831";
+pub const VALID_IBC_DENOM_2: &str =
+    "ibc/C4CFF46FD6DE35CA4CF4CE031E643C8FDC9BA4B99AE598E9B0ED98FE3A2319F9";
+pub const VALID_IBC_DENOM_3: &str =
+    "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2";
+
+pub type MockDeps = NeutronMockDeps;
 
-#[allow(clippy::type_complexity)]
 pub fn instantiate_wrapper(
     bridged_denom: impl Into<String>,
     canonical_subdenom: impl Into<String>,
-) -> (
-    ContractResult<Response<NeutronMsg>>,
-    OwnedDeps<MockStorage, MockApi, MockQuerier, NeutronQuery>,
-    Env,
-) {
-    let mut deps = OwnedDeps {
-        storage: MockStorage::default(),
-        api: MockApi::default(),
-        querier: MockQuerier::default(),
-        custom_query_type: PhantomData,
-    };
+) -> (ContractResult<Response<NeutronMsg>>, MockDeps, Env) {
+    let mut deps = mock_neutron_dependencies();
     let env = mock_env();
     (
         instantiate(
@@ -38,6 +34,7 @@ pub fn instantiate_wrapper(
             InstantiateMsg {
                 bridged_denom: bridged_denom.into(),
                 canonical_subdenom: canonical_subdenom.into(),
+                owner: None,
             },
         ),
         deps,
@@ -45,13 +42,84 @@ pub fn instantiate_wrapper(
     )
 }
 
+pub fn register_asset_wrapper(
+    deps: DepsMut<NeutronQuery>,
+    env: Env,
+    canonical_subdenom: &str,
+    bridged_denoms: &[&str],
+) {
+    execute(
+        deps,
+        env,
+        mock_info("admin", &[]),
+        ExecuteMsg::RegisterAsset {
+            canonical_subdenom: canonical_subdenom.to_string(),
+            bridged_denoms: bridged_denoms.iter().map(|d| d.to_string()).collect(),
+        },
+    )
+    .unwrap();
+}
+
+/// Mint of attached funds to `receiver`, or to sender if it's not set, with no optional
+/// fields filled
+pub fn mint_msg(receiver: Option<&str>) -> ExecuteMsg {
+    ExecuteMsg::Mint {
+        receiver: receiver.map(str::to_string),
+        idempotency_key: None,
+        source_chain: None,
+        source_address: None,
+        evm_sender: None,
+        ibc_hooks_origin: None,
+        referral: None,
+        memo: None,
+    }
+}
+
+/// Sets GMP config with an Axelar relayer and gateway on `channel-2`, through which
+/// [`VALID_IBC_DENOM`] arrives, on behalf of `sender`
+pub fn set_gmp_config(
+    deps: DepsMut<NeutronQuery>,
+    env: Env,
+    sender: &str,
+) -> ContractResult<Response<NeutronMsg>> {
+    execute(
+        deps,
+        env,
+        mock_info(sender, &[]),
+        ExecuteMsg::SetGmpConfig {
+            sender: "axelar_relayer".to_string(),
+            channel: "channel-2".to_string(),
+            gateway: "axelar_gateway".to_string(),
+            gas_service: "axelar_gas_service".to_string(),
+            bridged_denoms: vec![VALID_IBC_DENOM.to_string()],
+        },
+    )
+}
+
 pub fn assert_config(deps: Deps<NeutronQuery>, bridged_denom: &str, canonical_denom: &str) {
     let config = CONFIG.load(deps.storage).unwrap();
     assert_eq!(
         config,
         Config {
-            bridged_denom: bridged_denom.to_string(),
+            owner: Addr::unchecked("admin"),
+        }
+    );
+    assert_asset(deps, canonical_denom, &[bridged_denom], canonical_denom);
+}
+
+pub fn assert_asset(
+    deps: Deps<NeutronQuery>,
+    canonical_subdenom: &str,
+    bridged_denoms: &[&str],
+    canonical_denom: &str,
+) {
+    let asset = ASSETS.load(deps.storage, canonical_subdenom).unwrap();
+    assert_eq!(
+        asset,
+        Asset {
+            bridged_denoms: bridged_denoms.iter().map(|d| d.to_string()).collect(),
             canonical_denom: canonical_denom.to_string(),
+            routing_policy: RoutingPolicy::Priority,
         }
     )
 }
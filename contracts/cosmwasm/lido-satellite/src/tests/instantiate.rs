@@ -152,6 +152,7 @@ fn assert_create_denom_msg_and_attrs(
         vec![
             attr("bridged_denom", bridged_denom),
             attr("canonical_subdenom", canonical_subdenom),
+            attr("owner", "admin"),
         ]
     );
 }
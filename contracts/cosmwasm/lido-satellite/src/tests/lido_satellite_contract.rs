@@ -0,0 +1,76 @@
+use crate::{
+    helpers::LidoSatelliteContract,
+    msg::{ExecuteMsg, QueryMsg},
+    tests::helpers::mint_msg,
+};
+use cosmwasm_std::{
+    coin, from_json, testing::MockQuerier, to_json_binary, Addr, ContractResult, CosmosMsg, Empty,
+    QuerierWrapper, SystemResult, Uint128, WasmMsg, WasmQuery,
+};
+
+#[test]
+fn builds_mint_and_burn() {
+    let contract = LidoSatelliteContract(Addr::unchecked("satellite"));
+
+    let msg: CosmosMsg = contract
+        .mint(coin(10, "ibc/wsteth"), Some("receiver".to_string()))
+        .unwrap();
+    let CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr,
+        msg,
+        funds,
+    }) = msg
+    else {
+        panic!("unexpected message: {msg:?}");
+    };
+    assert_eq!(contract_addr, "satellite");
+    assert_eq!(funds, vec![coin(10, "ibc/wsteth")]);
+    assert_eq!(
+        from_json::<ExecuteMsg>(msg).unwrap(),
+        mint_msg(Some("receiver"))
+    );
+
+    let msg: CosmosMsg = contract
+        .burn(coin(5, "factory/satellite/wsteth"), None, None)
+        .unwrap();
+    assert_eq!(
+        msg,
+        WasmMsg::Execute {
+            contract_addr: "satellite".to_string(),
+            msg: to_json_binary(&ExecuteMsg::Burn {
+                receiver: None,
+                bridged_denom: None,
+                referral: None,
+                memo: None,
+            })
+            .unwrap(),
+            funds: vec![coin(5, "factory/satellite/wsteth")],
+        }
+        .into()
+    );
+}
+
+#[test]
+fn queries_contract() {
+    let contract = LidoSatelliteContract(Addr::unchecked("satellite"));
+    let mut querier = MockQuerier::<Empty>::new(&[]);
+    querier.update_wasm(|query| match query {
+        WasmQuery::Smart { contract_addr, msg } if contract_addr == "satellite" => {
+            match from_json(msg).unwrap() {
+                QueryMsg::ConvertToStEth { amount } => SystemResult::Ok(ContractResult::Ok(
+                    to_json_binary(&(amount * Uint128::new(2))).unwrap(),
+                )),
+                msg => panic!("unexpected query: {msg:?}"),
+            }
+        }
+        _ => panic!("unexpected query: {query:?}"),
+    });
+
+    let querier = QuerierWrapper::<Empty>::new(&querier);
+    assert_eq!(
+        contract
+            .convert_to_steth(&querier, Uint128::new(21))
+            .unwrap(),
+        Uint128::new(42)
+    );
+}
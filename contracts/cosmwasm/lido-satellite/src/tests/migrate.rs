@@ -0,0 +1,204 @@
+use crate::{
+    contract::{execute, migrate, query},
+    msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, SurplusResponse},
+    state::{Config, LegacyConfig, BRIDGES, CANONICAL_DENOMS, CONFIG, LEGACY_CONFIG},
+    testing::{instantiate_with_reply, mock_neutron_dependencies, NeutronMockDeps},
+    tests::helpers::{assert_asset, VALID_IBC_DENOM},
+    ContractError,
+};
+use cosmwasm_std::{
+    coin, from_json,
+    testing::{mock_env, mock_info},
+    Addr, BankMsg, Uint128,
+};
+
+const CANONICAL_DENOM: &str = "factory/cosmos2contract/wsteth";
+
+/// Sets up storage and balances like a single asset contract which minted 300 canonical funds
+fn legacy_deps() -> NeutronMockDeps {
+    legacy_deps_with_denom(CANONICAL_DENOM)
+}
+
+fn legacy_deps_with_denom(canonical_denom: &str) -> NeutronMockDeps {
+    let mut deps = mock_neutron_dependencies();
+    let contract = mock_env().contract.address;
+    LEGACY_CONFIG
+        .save(
+            deps.as_mut().storage,
+            &LegacyConfig {
+                bridged_denom: VALID_IBC_DENOM.to_string(),
+                canonical_denom: canonical_denom.to_string(),
+            },
+        )
+        .unwrap();
+    if let Some(subdenom) = canonical_denom.strip_prefix(&format!("factory/{contract}/")) {
+        deps.querier.create_denom(&contract, subdenom);
+    }
+    deps.querier
+        .add_balance(contract.as_str(), VALID_IBC_DENOM, Uint128::new(300));
+    deps.querier
+        .add_balance("user", canonical_denom, Uint128::new(300));
+    deps
+}
+
+#[test]
+fn moves_legacy_config_into_registry() {
+    let mut deps = legacy_deps();
+    let env = mock_env();
+
+    let response = migrate(
+        deps.as_mut(),
+        env.clone(),
+        MigrateMsg {
+            owner: Some("owner".to_string()),
+        },
+    )
+    .unwrap();
+    assert_eq!(response.attributes[1].value, "wsteth");
+    assert_eq!(response.attributes[2].value, "owner");
+
+    assert_eq!(
+        CONFIG.load(deps.as_ref().storage).unwrap(),
+        Config {
+            owner: Addr::unchecked("owner"),
+        }
+    );
+    assert_asset(deps.as_ref(), "wsteth", &[VALID_IBC_DENOM], CANONICAL_DENOM);
+    assert_eq!(
+        BRIDGES
+            .load(deps.as_ref().storage, VALID_IBC_DENOM)
+            .unwrap()
+            .canonical_subdenom,
+        "wsteth"
+    );
+    assert_eq!(
+        CANONICAL_DENOMS
+            .load(deps.as_ref().storage, CANONICAL_DENOM)
+            .unwrap(),
+        "wsteth"
+    );
+
+    // reserves back the canonical supply minted before migration
+    let surplus: Vec<SurplusResponse> =
+        from_json(query(deps.as_ref(), env.clone(), QueryMsg::Surplus {}).unwrap()).unwrap();
+    assert_eq!(
+        surplus,
+        vec![SurplusResponse {
+            denom: VALID_IBC_DENOM.to_string(),
+            balance: Uint128::new(300),
+            expected: Uint128::new(300),
+            surplus: Uint128::zero(),
+        }]
+    );
+
+    let response = execute(
+        deps.as_mut(),
+        env,
+        mock_info("user", &[coin(100, CANONICAL_DENOM)]),
+        ExecuteMsg::Burn {
+            receiver: None,
+            bridged_denom: None,
+            referral: None,
+            memo: None,
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        response.messages[1].msg,
+        BankMsg::Send {
+            to_address: "user".to_string(),
+            amount: vec![coin(100, VALID_IBC_DENOM)],
+        }
+        .into()
+    );
+}
+
+#[test]
+fn legacy_subdenom_with_slashes() {
+    let canonical_denom = "factory/cosmos2contract/lido/wsteth";
+    let mut deps = legacy_deps_with_denom(canonical_denom);
+    migrate(
+        deps.as_mut(),
+        mock_env(),
+        MigrateMsg {
+            owner: Some("owner".to_string()),
+        },
+    )
+    .unwrap();
+    assert_asset(
+        deps.as_ref(),
+        "lido/wsteth",
+        &[VALID_IBC_DENOM],
+        canonical_denom,
+    );
+    assert_eq!(
+        BRIDGES
+            .load(deps.as_ref().storage, VALID_IBC_DENOM)
+            .unwrap()
+            .canonical_subdenom,
+        "lido/wsteth"
+    );
+}
+
+#[test]
+fn legacy_denom_of_another_contract() {
+    let canonical_denom = "factory/cosmos2another/wsteth";
+    let mut deps = legacy_deps_with_denom(canonical_denom);
+    let err = migrate(
+        deps.as_mut(),
+        mock_env(),
+        MigrateMsg {
+            owner: Some("owner".to_string()),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::ForeignLegacyDenom {
+            denom: canonical_denom.to_string(),
+        }
+    );
+}
+
+#[test]
+fn legacy_config_requires_owner() {
+    let mut deps = legacy_deps();
+    let err = migrate(deps.as_mut(), mock_env(), MigrateMsg { owner: None }).unwrap_err();
+    assert_eq!(err, ContractError::MigrationOwnerRequired {});
+}
+
+#[test]
+fn current_config_is_kept() {
+    let mut deps = mock_neutron_dependencies();
+    let env = mock_env();
+    instantiate_with_reply(
+        &mut deps,
+        env.clone(),
+        mock_info("admin", &[]),
+        InstantiateMsg {
+            bridged_denom: VALID_IBC_DENOM.to_string(),
+            canonical_subdenom: "wsteth".to_string(),
+            owner: None,
+        },
+    )
+    .unwrap();
+
+    let err = migrate(
+        deps.as_mut(),
+        env.clone(),
+        MigrateMsg {
+            owner: Some("owner".to_string()),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::UnexpectedMigrationOwner {});
+
+    let response = migrate(deps.as_mut(), env, MigrateMsg { owner: None }).unwrap();
+    assert!(response.attributes.is_empty());
+    assert_eq!(
+        CONFIG.load(deps.as_ref().storage).unwrap(),
+        Config {
+            owner: Addr::unchecked("admin"),
+        }
+    );
+}
@@ -1,4 +1,9 @@
 mod execute;
 mod helpers;
 mod instantiate;
+mod lido_satellite_contract;
+mod migrate;
+mod multitest;
 mod query;
+mod reply;
+mod sudo;
@@ -0,0 +1,190 @@
+use super::{
+    instantiate_lido_satellite, neutron_app, tokenfactory::TokenFactory, ADMIN, INITIAL_BALANCE,
+    USER,
+};
+use crate::{
+    msg::{ExecuteMsg, HealthResponse, QueryMsg, SudoMsg},
+    tests::helpers::{mint_msg, VALID_IBC_DENOM},
+};
+use cosmwasm_std::{coin, coins, Addr, CosmosMsg, DenomMetadata, DenomUnit, QueryRequest};
+use cw_multi_test::Executor;
+use neutron_sdk::{
+    bindings::{msg::NeutronMsg, query::NeutronQuery},
+    query::token_factory::DenomAdminResponse,
+};
+
+fn burn_msg() -> ExecuteMsg {
+    ExecuteMsg::Burn {
+        receiver: None,
+        bridged_denom: None,
+        referral: None,
+        memo: None,
+    }
+}
+
+#[test]
+fn instantiate_mint_burn() {
+    let mut app = neutron_app();
+    let (contract, canonical_denom) = instantiate_lido_satellite(&mut app);
+    assert_eq!(canonical_denom, format!("factory/{contract}/wsteth"));
+    let admin: DenomAdminResponse = app
+        .wrap()
+        .query(&QueryRequest::Custom(NeutronQuery::DenomAdmin {
+            subdenom: canonical_denom.clone(),
+        }))
+        .unwrap();
+    assert_eq!(admin.admin, contract.as_str());
+
+    let user = Addr::unchecked(USER);
+    app.execute_contract(
+        user.clone(),
+        contract.clone(),
+        &mint_msg(None),
+        &coins(300, VALID_IBC_DENOM),
+    )
+    .unwrap();
+    assert_eq!(
+        app.wrap().query_balance(USER, &canonical_denom).unwrap(),
+        coin(300, &canonical_denom)
+    );
+    assert_eq!(
+        app.wrap().query_supply(&canonical_denom).unwrap(),
+        coin(300, &canonical_denom)
+    );
+
+    app.execute_contract(
+        user,
+        contract.clone(),
+        &burn_msg(),
+        &coins(100, &canonical_denom),
+    )
+    .unwrap();
+    assert_eq!(
+        app.wrap().query_all_balances(USER).unwrap(),
+        vec![
+            coin(200, &canonical_denom),
+            coin(INITIAL_BALANCE - 200, VALID_IBC_DENOM),
+        ]
+    );
+    assert_eq!(
+        app.wrap().query_all_balances(&contract).unwrap(),
+        coins(200, VALID_IBC_DENOM)
+    );
+    assert_eq!(
+        app.wrap().query_supply(&canonical_denom).unwrap(),
+        coin(200, &canonical_denom)
+    );
+
+    app.wasm_sudo(contract.clone(), &SudoMsg::HealthCheck {})
+        .unwrap();
+    let health: HealthResponse = app
+        .wrap()
+        .query_wasm_smart(&contract, &QueryMsg::Health {})
+        .unwrap();
+    assert_eq!(
+        health.last_check.unwrap().undercollateralized_assets,
+        Vec::<String>::new()
+    );
+}
+
+#[test]
+fn burn_more_than_minted() {
+    let mut app = neutron_app();
+    let (contract, canonical_denom) = instantiate_lido_satellite(&mut app);
+    let user = Addr::unchecked(USER);
+    app.execute_contract(
+        user.clone(),
+        contract.clone(),
+        &mint_msg(None),
+        &coins(100, VALID_IBC_DENOM),
+    )
+    .unwrap();
+
+    // bank refuses to transfer more canonical funds than the user holds
+    app.execute_contract(user, contract, &burn_msg(), &coins(101, &canonical_denom))
+        .unwrap_err();
+    assert_eq!(
+        app.wrap().query_supply(&canonical_denom).unwrap(),
+        coin(100, &canonical_denom)
+    );
+}
+
+#[test]
+fn migrate_to_successor() {
+    let mut app = neutron_app();
+    let (contract, canonical_denom) = instantiate_lido_satellite(&mut app);
+    app.execute_contract(
+        Addr::unchecked(USER),
+        contract.clone(),
+        &mint_msg(None),
+        &coins(100, VALID_IBC_DENOM),
+    )
+    .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        contract.clone(),
+        &ExecuteMsg::MigrateToSuccessor {
+            successor: "successor".to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+    let admin: DenomAdminResponse = app
+        .wrap()
+        .query(&QueryRequest::Custom(NeutronQuery::DenomAdmin {
+            subdenom: canonical_denom,
+        }))
+        .unwrap();
+    assert_eq!(admin.admin, "successor");
+    assert_eq!(
+        app.wrap().query_all_balances("successor").unwrap(),
+        coins(100, VALID_IBC_DENOM)
+    );
+    assert_eq!(app.wrap().query_all_balances(&contract).unwrap(), vec![]);
+}
+
+#[test]
+fn tokenfactory_checks_admin() {
+    let mut app = neutron_app();
+    let (contract, canonical_denom) = instantiate_lido_satellite(&mut app);
+
+    let mint = CosmosMsg::Custom(NeutronMsg::submit_mint_tokens(
+        &canonical_denom,
+        100u128.into(),
+        USER,
+    ));
+    app.execute(Addr::unchecked(USER), mint).unwrap_err();
+
+    let metadata = DenomMetadata {
+        description: "Wrapped liquid staked Ether".to_string(),
+        denom_units: vec![DenomUnit {
+            denom: canonical_denom.clone(),
+            exponent: 0,
+            aliases: vec![],
+        }],
+        base: canonical_denom.clone(),
+        display: canonical_denom.clone(),
+        name: "wstETH".to_string(),
+        symbol: "WSTETH".to_string(),
+        uri: String::new(),
+        uri_hash: String::new(),
+    };
+    let set_metadata = CosmosMsg::Custom(NeutronMsg::SetDenomMetadata {
+        description: metadata.description.clone(),
+        denom_units: metadata.denom_units.clone(),
+        base: metadata.base.clone(),
+        display: metadata.display.clone(),
+        name: metadata.name.clone(),
+        symbol: metadata.symbol.clone(),
+        uri: metadata.uri.clone(),
+        uri_hash: metadata.uri_hash.clone(),
+    });
+    app.execute(Addr::unchecked(USER), set_metadata.clone())
+        .unwrap_err();
+    app.execute(contract, set_metadata).unwrap();
+    let stored = app.read_module(|_router, _api, storage| {
+        TokenFactory::denom_metadata(storage, &canonical_denom).unwrap()
+    });
+    assert_eq!(stored, Some(metadata));
+}
@@ -0,0 +1,93 @@
+mod lifecycle;
+mod tokenfactory;
+
+use crate::{
+    contract::{execute, instantiate, query, reply, sudo},
+    msg::{ConfigResponse, InstantiateMsg, QueryMsg},
+    tests::helpers::VALID_IBC_DENOM,
+    ContractResult,
+};
+use cosmwasm_std::{
+    coins,
+    testing::{MockApi, MockStorage},
+    Addr, DepsMut, Env, Reply, Response,
+};
+use cw_multi_test::{App, AppBuilder, BankKeeper, Contract, ContractWrapper, Executor, WasmKeeper};
+use neutron_sdk::bindings::{msg::NeutronMsg, query::NeutronQuery};
+use tokenfactory::TokenFactory;
+
+pub type NeutronApp =
+    App<BankKeeper, MockApi, MockStorage, TokenFactory, WasmKeeper<NeutronMsg, NeutronQuery>>;
+
+pub const USER: &str = "user";
+pub const ADMIN: &str = "admin";
+
+/// Bridged funds every user starts with
+pub const INITIAL_BALANCE: u128 = 1000;
+
+fn lido_satellite() -> Box<dyn Contract<NeutronMsg, NeutronQuery>> {
+    Box::new(
+        ContractWrapper::new(execute, instantiate, query)
+            .with_sudo(sudo)
+            .with_reply(reply_with_neutron_msg),
+    )
+}
+
+/// Replies of the contract never send messages, so they can be handed over to multi-test
+/// as if they were sending Neutron messages
+fn reply_with_neutron_msg(
+    deps: DepsMut<NeutronQuery>,
+    env: Env,
+    msg: Reply,
+) -> ContractResult<Response<NeutronMsg>> {
+    let response = reply(deps, env, msg)?;
+    assert!(response.messages.is_empty());
+    let mut converted = Response::new()
+        .add_attributes(response.attributes)
+        .add_events(response.events);
+    if let Some(data) = response.data {
+        converted = converted.set_data(data);
+    }
+    Ok(converted)
+}
+
+pub fn neutron_app() -> NeutronApp {
+    AppBuilder::new_custom()
+        .with_custom(TokenFactory::default())
+        .build(|router, _api, storage| {
+            router
+                .bank
+                .init_balance(
+                    storage,
+                    &Addr::unchecked(USER),
+                    coins(INITIAL_BALANCE, VALID_IBC_DENOM),
+                )
+                .unwrap();
+        })
+}
+
+/// Instantiates Lido Satellite backed by [`VALID_IBC_DENOM`], returning its address
+/// and canonical denom
+pub fn instantiate_lido_satellite(app: &mut NeutronApp) -> (Addr, String) {
+    let code_id = app.store_code(lido_satellite());
+    let contract = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked(ADMIN),
+            &InstantiateMsg {
+                bridged_denom: VALID_IBC_DENOM.to_string(),
+                canonical_subdenom: "wsteth".to_string(),
+                owner: None,
+            },
+            &[],
+            "lido-satellite",
+            Some(ADMIN.to_string()),
+        )
+        .unwrap();
+    let config: ConfigResponse = app
+        .wrap()
+        .query_wasm_smart(&contract, &QueryMsg::Config {})
+        .unwrap();
+    let canonical_denom = config.assets[0].canonical_denom.clone();
+    (contract, canonical_denom)
+}
@@ -0,0 +1,182 @@
+use anyhow::{bail, ensure, Result as AnyResult};
+use cosmwasm_schema::{schemars::JsonSchema, serde::de::DeserializeOwned};
+use cosmwasm_std::{
+    coins, to_json_binary, Addr, Api, BankMsg, Binary, BlockInfo, CustomQuery, DenomMetadata,
+    Empty, Querier, Storage,
+};
+use cw_multi_test::{AppResponse, BankSudo, CosmosRouter, Module};
+use cw_storage_plus::Map;
+use neutron_sdk::{
+    bindings::{msg::NeutronMsg, query::NeutronQuery},
+    query::token_factory::{DenomAdminResponse, FullDenomResponse},
+};
+use std::fmt::Debug;
+
+/// Admins of tokenfactory denoms, keyed by full denom
+const DENOM_ADMINS: Map<&str, Addr> = Map::new("tokenfactory_denom_admins");
+
+/// Metadata of tokenfactory denoms, keyed by full denom
+const DENOM_METADATA: Map<&str, DenomMetadata> = Map::new("tokenfactory_denom_metadata");
+
+/// Emulates Neutron tokenfactory module: its messages and `NeutronQuery` queries of full denom
+/// and denom admin. Tokens are minted and burned through the bank module, so balances and supply
+/// stay consistent. Other Neutron messages and queries fail.
+#[derive(Default)]
+pub struct TokenFactory {}
+
+impl TokenFactory {
+    pub fn full_denom(creator: &str, subdenom: &str) -> String {
+        format!("factory/{creator}/{subdenom}")
+    }
+
+    pub fn denom_admin(storage: &dyn Storage, denom: &str) -> AnyResult<Option<Addr>> {
+        Ok(DENOM_ADMINS.may_load(storage, denom)?)
+    }
+
+    pub fn denom_metadata(storage: &dyn Storage, denom: &str) -> AnyResult<Option<DenomMetadata>> {
+        Ok(DENOM_METADATA.may_load(storage, denom)?)
+    }
+
+    fn ensure_admin(storage: &dyn Storage, sender: &Addr, denom: &str) -> AnyResult<()> {
+        match DENOM_ADMINS.may_load(storage, denom)? {
+            None => bail!("denom {denom} does not exist"),
+            Some(admin) => ensure!(admin == sender, "{sender} is not an admin of {denom}"),
+        }
+        Ok(())
+    }
+}
+
+impl Module for TokenFactory {
+    type ExecT = NeutronMsg;
+    type QueryT = NeutronQuery;
+    type SudoT = Empty;
+
+    fn execute<ExecC, QueryC>(
+        &self,
+        api: &dyn Api,
+        storage: &mut dyn Storage,
+        router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        block: &BlockInfo,
+        sender: Addr,
+        msg: NeutronMsg,
+    ) -> AnyResult<AppResponse>
+    where
+        ExecC: Debug + Clone + PartialEq + JsonSchema + DeserializeOwned + 'static,
+        QueryC: CustomQuery + DeserializeOwned + 'static,
+    {
+        match msg {
+            NeutronMsg::CreateDenom { subdenom } => {
+                let denom = Self::full_denom(sender.as_str(), &subdenom);
+                ensure!(
+                    !DENOM_ADMINS.has(storage, &denom),
+                    "denom {denom} already exists"
+                );
+                DENOM_ADMINS.save(storage, &denom, &sender)?;
+                Ok(AppResponse::default())
+            }
+            NeutronMsg::ChangeAdmin {
+                denom,
+                new_admin_address,
+            } => {
+                Self::ensure_admin(storage, &sender, &denom)?;
+                let new_admin = api.addr_validate(&new_admin_address)?;
+                DENOM_ADMINS.save(storage, &denom, &new_admin)?;
+                Ok(AppResponse::default())
+            }
+            NeutronMsg::MintTokens {
+                denom,
+                amount,
+                mint_to_address,
+            } => {
+                Self::ensure_admin(storage, &sender, &denom)?;
+                let mint = BankSudo::Mint {
+                    to_address: mint_to_address,
+                    amount: coins(amount.u128(), denom),
+                };
+                router.sudo(api, storage, block, mint.into())
+            }
+            NeutronMsg::BurnTokens {
+                denom,
+                amount,
+                burn_from_address,
+            } => {
+                Self::ensure_admin(storage, &sender, &denom)?;
+                // Neutron only supports burning from the admin itself
+                ensure!(
+                    burn_from_address.is_empty() || burn_from_address == sender.as_str(),
+                    "burning from {burn_from_address} is not supported"
+                );
+                let burn = BankMsg::Burn {
+                    amount: coins(amount.u128(), denom),
+                };
+                router.execute(api, storage, block, sender, burn.into())
+            }
+            NeutronMsg::SetDenomMetadata {
+                description,
+                denom_units,
+                base,
+                display,
+                name,
+                symbol,
+                uri,
+                uri_hash,
+            } => {
+                Self::ensure_admin(storage, &sender, &base)?;
+                let metadata = DenomMetadata {
+                    description,
+                    denom_units,
+                    base,
+                    display,
+                    name,
+                    symbol,
+                    uri,
+                    uri_hash,
+                };
+                DENOM_METADATA.save(storage, &metadata.base, &metadata)?;
+                Ok(AppResponse::default())
+            }
+            msg => bail!("unsupported Neutron message: {msg:?}"),
+        }
+    }
+
+    fn query(
+        &self,
+        _api: &dyn Api,
+        storage: &dyn Storage,
+        _querier: &dyn Querier,
+        _block: &BlockInfo,
+        request: NeutronQuery,
+    ) -> AnyResult<Binary> {
+        match request {
+            NeutronQuery::FullDenom {
+                creator_addr,
+                subdenom,
+            } => Ok(to_json_binary(&FullDenomResponse {
+                denom: Self::full_denom(&creator_addr, &subdenom),
+            })?),
+            // despite its name, the query expects a full denom
+            NeutronQuery::DenomAdmin { subdenom } => match Self::denom_admin(storage, &subdenom)? {
+                Some(admin) => Ok(to_json_binary(&DenomAdminResponse {
+                    admin: admin.into_string(),
+                })?),
+                None => bail!("denom {subdenom} does not exist"),
+            },
+            request => bail!("unsupported Neutron query: {request:?}"),
+        }
+    }
+
+    fn sudo<ExecC, QueryC>(
+        &self,
+        _api: &dyn Api,
+        _storage: &mut dyn Storage,
+        _router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        _block: &BlockInfo,
+        msg: Empty,
+    ) -> AnyResult<AppResponse>
+    where
+        ExecC: Debug + Clone + PartialEq + JsonSchema + DeserializeOwned + 'static,
+        QueryC: CustomQuery + DeserializeOwned + 'static,
+    {
+        bail!("unsupported sudo message: {msg:?}")
+    }
+}
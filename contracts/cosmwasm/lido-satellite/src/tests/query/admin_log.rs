@@ -0,0 +1,106 @@
+use crate::{
+    contract::{execute, query},
+    msg::{AdminLogEntryResponse, ExecuteMsg, QueryMsg},
+    state::{BridgeStatus, Role},
+    tests::helpers::{instantiate_wrapper, VALID_IBC_DENOM},
+};
+use cosmwasm_std::{from_json, testing::mock_info};
+
+#[test]
+fn records_privileged_actions() {
+    let (_result, mut deps, mut env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    env.block.height += 1;
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("admin", &[]),
+        ExecuteMsg::SetBridgeStatus {
+            bridged_denom: VALID_IBC_DENOM.to_string(),
+            status: BridgeStatus::BurnOnly,
+        },
+    )
+    .unwrap();
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("admin", &[]),
+        ExecuteMsg::GrantRole {
+            role: Role::Guardian,
+            address: "guardian".to_string(),
+        },
+    )
+    .unwrap();
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("guardian", &[]),
+        ExecuteMsg::Pause {},
+    )
+    .unwrap();
+
+    let page = |start_after, limit| -> Vec<AdminLogEntryResponse> {
+        from_json(
+            query(
+                deps.as_ref(),
+                env.clone(),
+                QueryMsg::AdminLog { start_after, limit },
+            )
+            .unwrap(),
+        )
+        .unwrap()
+    };
+    let entry =
+        |id, actor: &str, height, action: &str, old_value: Option<&str>, new_value: &str| {
+            AdminLogEntryResponse {
+                id,
+                actor: actor.to_string(),
+                height,
+                time: env.block.time,
+                action: action.to_string(),
+                old_value: old_value.map(String::from),
+                new_value: Some(new_value.to_string()),
+            }
+        };
+    assert_eq!(
+        page(None, Some(2)),
+        vec![
+            entry(
+                0,
+                "admin",
+                env.block.height - 1,
+                "instantiate",
+                None,
+                r#"{"owner":"admin"}"#,
+            ),
+            entry(
+                1,
+                "admin",
+                env.block.height,
+                "set_bridge_status",
+                Some(r#""active""#),
+                r#""burn_only""#,
+            ),
+        ]
+    );
+    assert_eq!(
+        page(Some(1), None),
+        vec![
+            entry(
+                2,
+                "admin",
+                env.block.height,
+                "grant_role",
+                None,
+                r#"["guardian","guardian"]"#,
+            ),
+            entry(
+                3,
+                "guardian",
+                env.block.height,
+                "pause",
+                Some("false"),
+                "true"
+            ),
+        ]
+    );
+}
@@ -0,0 +1,58 @@
+use crate::{
+    contract::query,
+    msg::{AssetResponse, BridgeResponse, QueryMsg},
+    state::{BridgeStatus, RoutingPolicy},
+    tests::helpers::{instantiate_wrapper, VALID_IBC_DENOM},
+    ContractError,
+};
+use cosmwasm_std::from_json;
+
+#[test]
+fn registered() {
+    let (_result, deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    let asset_response: AssetResponse = from_json(
+        query(
+            deps.as_ref(),
+            env,
+            QueryMsg::Asset {
+                canonical_subdenom: "eth".to_string(),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        asset_response,
+        AssetResponse {
+            canonical_subdenom: "eth".to_string(),
+            canonical_denom: "eth".to_string(),
+            bridges: vec![BridgeResponse {
+                bridged_denom: VALID_IBC_DENOM.to_string(),
+                status: BridgeStatus::Active,
+                min_amount: None,
+                max_amount: None,
+            }],
+            routing_policy: RoutingPolicy::Priority,
+            withdrawal_queue: None,
+        }
+    );
+}
+
+#[test]
+fn not_registered() {
+    let (_result, deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    let err = query(
+        deps.as_ref(),
+        env,
+        QueryMsg::Asset {
+            canonical_subdenom: "steth".to_string(),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::AssetNotRegistered {
+            subdenom: "steth".to_string()
+        }
+    );
+}
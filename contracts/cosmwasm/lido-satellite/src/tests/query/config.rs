@@ -1,6 +1,7 @@
 use crate::{
     contract::query,
-    msg::{ConfigResponse, QueryMsg},
+    msg::{AssetResponse, BridgeResponse, ConfigResponse, QueryMsg},
+    state::{BridgeStatus, RoutingPolicy},
     tests::helpers::{instantiate_wrapper, VALID_IBC_DENOM},
 };
 use cosmwasm_std::from_json;
@@ -13,8 +14,25 @@ fn corresponds_to_instantiate_params() {
     assert_eq!(
         config_response,
         ConfigResponse {
-            bridged_denom: VALID_IBC_DENOM.to_string(),
-            canonical_denom: "eth".to_string(),
+            owner: "admin".to_string(),
+            paused: false,
+            timelock_delay: 0,
+            successor: None,
+            assets: vec![AssetResponse {
+                canonical_subdenom: "eth".to_string(),
+                canonical_denom: "eth".to_string(),
+                bridges: vec![BridgeResponse {
+                    bridged_denom: VALID_IBC_DENOM.to_string(),
+                    status: BridgeStatus::Active,
+                    min_amount: None,
+                    max_amount: None,
+                }],
+                routing_policy: RoutingPolicy::Priority,
+                withdrawal_queue: None,
+            }],
+            gmp: None,
+            attestation: None,
+            last_attestation: None,
         }
     );
 }
@@ -0,0 +1,65 @@
+use crate::{
+    contract::query,
+    msg::{ExchangeRateResponse, QueryMsg},
+    state::{ExchangeRate, EXCHANGE_RATE},
+    tests::helpers::{instantiate_wrapper, VALID_IBC_DENOM},
+    ContractError,
+};
+use cosmwasm_std::{from_json, Decimal256, Uint128};
+use std::str::FromStr;
+
+#[test]
+fn not_set() {
+    let (_result, deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    let err = query(deps.as_ref(), env.clone(), QueryMsg::ExchangeRate {}).unwrap_err();
+    assert_eq!(err, ContractError::ExchangeRateNotSet {});
+    let err = query(
+        deps.as_ref(),
+        env,
+        QueryMsg::ConvertToStEth {
+            amount: Uint128::new(100),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::ExchangeRateNotSet {});
+}
+
+#[test]
+fn rate_and_conversion() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    let exchange_rate = ExchangeRate {
+        rate: Decimal256::from_str("1.151234").unwrap(),
+        block_number: 100,
+        block_timestamp: 1_700_000_000,
+        updated_at: env.block.time,
+    };
+    EXCHANGE_RATE
+        .save(deps.as_mut().storage, &exchange_rate)
+        .unwrap();
+
+    let response: ExchangeRateResponse =
+        from_json(query(deps.as_ref(), env.clone(), QueryMsg::ExchangeRate {}).unwrap()).unwrap();
+    assert_eq!(
+        response,
+        ExchangeRateResponse {
+            rate: exchange_rate.rate,
+            block_number: 100,
+            block_timestamp: 1_700_000_000,
+            updated_at: env.block.time,
+        }
+    );
+
+    let steth_amount: Uint128 = from_json(
+        query(
+            deps.as_ref(),
+            env,
+            QueryMsg::ConvertToStEth {
+                amount: Uint128::new(1_000),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    // 1151.234 is rounded down
+    assert_eq!(steth_amount, Uint128::new(1_151));
+}
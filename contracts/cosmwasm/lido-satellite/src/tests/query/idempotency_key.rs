@@ -0,0 +1,72 @@
+use crate::{
+    contract::{execute, query},
+    msg::{ExecuteMsg, IdempotencyKeyResponse, QueryMsg, IDEMPOTENCY_KEY_TTL},
+    tests::helpers::{instantiate_wrapper, VALID_IBC_DENOM},
+};
+use cosmwasm_std::{coin, from_json, testing::mock_info, Deps, Env};
+use neutron_sdk::bindings::query::NeutronQuery;
+
+fn query_key(deps: Deps<NeutronQuery>, env: Env, key: &str) -> IdempotencyKeyResponse {
+    from_json(
+        query(
+            deps,
+            env,
+            QueryMsg::IdempotencyKey {
+                sender: "stranger".to_string(),
+                key: key.to_string(),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap()
+}
+
+#[test]
+fn not_processed() {
+    let (_result, deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    assert_eq!(
+        query_key(deps.as_ref(), env, "command-1"),
+        IdempotencyKeyResponse {
+            processed: false,
+            expires_at: None,
+        }
+    );
+}
+
+#[test]
+fn processed_and_expired() {
+    let (_result, mut deps, mut env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("stranger", &[coin(10, VALID_IBC_DENOM)]),
+        ExecuteMsg::Mint {
+            receiver: None,
+            idempotency_key: Some("command-1".to_string()),
+            source_chain: None,
+            source_address: None,
+            evm_sender: None,
+            ibc_hooks_origin: None,
+            referral: None,
+            memo: None,
+        },
+    )
+    .unwrap();
+    let expires_at = env.block.time.plus_seconds(IDEMPOTENCY_KEY_TTL);
+    assert_eq!(
+        query_key(deps.as_ref(), env.clone(), "command-1"),
+        IdempotencyKeyResponse {
+            processed: true,
+            expires_at: Some(expires_at),
+        }
+    );
+
+    env.block.time = expires_at;
+    assert_eq!(
+        query_key(deps.as_ref(), env, "command-1"),
+        IdempotencyKeyResponse {
+            processed: false,
+            expires_at: None,
+        }
+    );
+}
@@ -1 +1,8 @@
+mod admin_log;
+mod asset;
 mod config;
+mod exchange_rate;
+mod idempotency_key;
+mod proof_of_reserve;
+mod queued_changes;
+mod roles;
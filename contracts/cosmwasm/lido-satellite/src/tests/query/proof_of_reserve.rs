@@ -0,0 +1,74 @@
+use crate::{
+    contract::{execute, query, reply, REGISTER_ESCROW_QUERY_REPLY_ID},
+    msg::{EscrowReserveResponse, ExecuteMsg, QueryMsg},
+    state::{EscrowBalance, ESCROW_BALANCES},
+    tests::helpers::{instantiate_wrapper, VALID_IBC_DENOM},
+};
+use cosmwasm_std::{
+    coin, from_json, testing::mock_info, to_json_binary, Reply, SubMsgResponse, SubMsgResult,
+    Uint128,
+};
+use neutron_sdk::bindings::msg::MsgRegisterInterchainQueryResponse;
+
+const ESCROW_ADDRESS: &str = "axelar1qypqxpq9qcrsszg2pvxq6rs0zqg3yyc5mv6kda";
+
+#[test]
+fn compares_remote_escrow_with_local_reserves() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    deps.querier
+        .base
+        .update_balance(&env.contract.address, vec![coin(100, VALID_IBC_DENOM)]);
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("admin", &[]),
+        ExecuteMsg::RegisterEscrowQuery {
+            bridged_denom: VALID_IBC_DENOM.to_string(),
+            connection_id: "connection-0".to_string(),
+            escrow_address: ESCROW_ADDRESS.to_string(),
+            remote_denom: "wei".to_string(),
+            update_period: 100,
+        },
+    )
+    .unwrap();
+    reply(
+        deps.as_mut(),
+        env.clone(),
+        Reply {
+            id: REGISTER_ESCROW_QUERY_REPLY_ID,
+            result: SubMsgResult::Ok(SubMsgResponse {
+                events: vec![],
+                data: Some(to_json_binary(&MsgRegisterInterchainQueryResponse { id: 7 }).unwrap()),
+            }),
+        },
+    )
+    .unwrap();
+
+    let mut expected = EscrowReserveResponse {
+        bridged_denom: VALID_IBC_DENOM.to_string(),
+        connection_id: "connection-0".to_string(),
+        escrow_address: ESCROW_ADDRESS.to_string(),
+        remote_denom: "wei".to_string(),
+        query_id: Some(7),
+        remote_balance: None,
+        local_reserves: Uint128::new(100),
+        fully_backed: None,
+    };
+    let response: Vec<EscrowReserveResponse> =
+        from_json(query(deps.as_ref(), env.clone(), QueryMsg::ProofOfReserve {}).unwrap()).unwrap();
+    assert_eq!(response, vec![expected.clone()]);
+
+    let escrow_balance = EscrowBalance {
+        amount: Uint128::new(90),
+        remote_height: 1_000,
+        local_height: 2_000,
+    };
+    ESCROW_BALANCES
+        .save(deps.as_mut().storage, VALID_IBC_DENOM, &escrow_balance)
+        .unwrap();
+    let response: Vec<EscrowReserveResponse> =
+        from_json(query(deps.as_ref(), env, QueryMsg::ProofOfReserve {}).unwrap()).unwrap();
+    expected.remote_balance = Some(escrow_balance);
+    expected.fully_backed = Some(false);
+    assert_eq!(response, vec![expected]);
+}
@@ -0,0 +1,61 @@
+use crate::{
+    contract::{execute, query},
+    msg::{ExecuteMsg, QueryMsg, QueuedChangeResponse},
+    state::ConfigChange,
+    tests::helpers::{instantiate_wrapper, VALID_IBC_DENOM},
+};
+use cosmwasm_std::{from_json, testing::mock_info};
+
+#[test]
+fn paginated() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    for delay in 0..3 {
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("admin", &[]),
+            ExecuteMsg::QueueChange {
+                change: ConfigChange::SetTimelockDelay { delay },
+            },
+        )
+        .unwrap();
+    }
+
+    let page = |start_after, limit| -> Vec<QueuedChangeResponse> {
+        from_json(
+            query(
+                deps.as_ref(),
+                env.clone(),
+                QueryMsg::QueuedChanges { start_after, limit },
+            )
+            .unwrap(),
+        )
+        .unwrap()
+    };
+    assert_eq!(
+        page(None, Some(2)),
+        vec![
+            QueuedChangeResponse {
+                id: 0,
+                change: ConfigChange::SetTimelockDelay { delay: 0 },
+                proposer: "admin".to_string(),
+                eta: env.block.time,
+            },
+            QueuedChangeResponse {
+                id: 1,
+                change: ConfigChange::SetTimelockDelay { delay: 1 },
+                proposer: "admin".to_string(),
+                eta: env.block.time,
+            },
+        ]
+    );
+    assert_eq!(
+        page(Some(1), None),
+        vec![QueuedChangeResponse {
+            id: 2,
+            change: ConfigChange::SetTimelockDelay { delay: 2 },
+            proposer: "admin".to_string(),
+            eta: env.block.time,
+        }]
+    );
+}
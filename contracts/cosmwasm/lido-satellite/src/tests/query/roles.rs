@@ -0,0 +1,51 @@
+use crate::{
+    contract::{execute, query},
+    msg::{ExecuteMsg, QueryMsg, RoleResponse, RolesResponse},
+    state::Role,
+    tests::helpers::{instantiate_wrapper, VALID_IBC_DENOM},
+};
+use cosmwasm_std::{from_json, testing::mock_info};
+
+#[test]
+fn lists_holders_of_every_role() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    for (role, address) in [
+        (Role::Guardian, "guardian_2"),
+        (Role::Guardian, "guardian_1"),
+        (Role::BridgeManager, "manager"),
+    ] {
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("admin", &[]),
+            ExecuteMsg::GrantRole {
+                role,
+                address: address.to_string(),
+            },
+        )
+        .unwrap();
+    }
+
+    let response: RolesResponse =
+        from_json(query(deps.as_ref(), env, QueryMsg::Roles {}).unwrap()).unwrap();
+    assert_eq!(
+        response,
+        RolesResponse {
+            owner: "admin".to_string(),
+            roles: vec![
+                RoleResponse {
+                    role: Role::Guardian,
+                    holders: vec!["guardian_1".to_string(), "guardian_2".to_string()],
+                },
+                RoleResponse {
+                    role: Role::FeeManager,
+                    holders: vec![],
+                },
+                RoleResponse {
+                    role: Role::BridgeManager,
+                    holders: vec!["manager".to_string()],
+                },
+            ],
+        }
+    );
+}
@@ -0,0 +1,129 @@
+use crate::{
+    contract::{execute, query, sudo},
+    msg::{AssetResponse, ExecuteMsg, HealthResponse, InstantiateMsg, QueryMsg, SudoMsg},
+    testing::{instantiate_with_reply, mock_neutron_dependencies, process_create_denom},
+    tests::helpers::{mint_msg, VALID_IBC_DENOM, VALID_IBC_DENOM_2},
+};
+use cosmwasm_std::{
+    coin, from_json,
+    testing::{mock_env, mock_info},
+    Uint128,
+};
+
+#[test]
+fn creates_canonical_denom() {
+    let mut deps = mock_neutron_dependencies();
+    let env = mock_env();
+    let (_response, canonical_denom) = instantiate_with_reply(
+        &mut deps,
+        env.clone(),
+        mock_info("admin", &[]),
+        InstantiateMsg {
+            bridged_denom: VALID_IBC_DENOM.to_string(),
+            canonical_subdenom: "wsteth".to_string(),
+            owner: None,
+        },
+    )
+    .unwrap();
+    assert_eq!(canonical_denom, "factory/cosmos2contract/wsteth");
+
+    let response = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("admin", &[]),
+        ExecuteMsg::RegisterAsset {
+            canonical_subdenom: "steth".to_string(),
+            bridged_denoms: vec![VALID_IBC_DENOM_2.to_string()],
+        },
+    )
+    .unwrap();
+    process_create_denom(&mut deps, env.clone(), &response).unwrap();
+
+    let asset: AssetResponse = from_json(
+        query(
+            deps.as_ref(),
+            env,
+            QueryMsg::Asset {
+                canonical_subdenom: "steth".to_string(),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(asset.canonical_denom, "factory/cosmos2contract/steth");
+}
+
+#[test]
+fn mint_and_burn_change_supply() {
+    let mut deps = mock_neutron_dependencies();
+    let env = mock_env();
+    let contract = env.contract.address.clone();
+    let (_response, canonical_denom) = instantiate_with_reply(
+        &mut deps,
+        env.clone(),
+        mock_info("admin", &[]),
+        InstantiateMsg {
+            bridged_denom: VALID_IBC_DENOM.to_string(),
+            canonical_subdenom: "wsteth".to_string(),
+            owner: None,
+        },
+    )
+    .unwrap();
+
+    // funds attached to a message are transferred before it is executed
+    deps.querier
+        .add_balance(contract.as_str(), VALID_IBC_DENOM, Uint128::new(100));
+    let response = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("user", &[coin(100, VALID_IBC_DENOM)]),
+        mint_msg(None),
+    )
+    .unwrap();
+    deps.querier.apply_messages(&contract, &response);
+    assert_eq!(
+        deps.querier.balance("user", &canonical_denom),
+        Uint128::new(100)
+    );
+
+    deps.querier
+        .remove_balance("user", &canonical_denom, Uint128::new(40));
+    deps.querier
+        .add_balance(contract.as_str(), &canonical_denom, Uint128::new(40));
+    let response = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("user", &[coin(40, &canonical_denom)]),
+        ExecuteMsg::Burn {
+            receiver: None,
+            bridged_denom: None,
+            referral: None,
+            memo: None,
+        },
+    )
+    .unwrap();
+    deps.querier.apply_messages(&contract, &response);
+    assert_eq!(
+        deps.querier.balance("user", VALID_IBC_DENOM),
+        Uint128::new(40)
+    );
+    assert_eq!(
+        deps.querier.balance(contract.as_str(), VALID_IBC_DENOM),
+        Uint128::new(60)
+    );
+
+    // supply stays fully backed by bridged reserves
+    let supply = deps
+        .as_ref()
+        .querier
+        .query_supply(&canonical_denom)
+        .unwrap();
+    assert_eq!(supply, coin(60, &canonical_denom));
+    sudo(deps.as_mut(), env.clone(), SudoMsg::HealthCheck {}).unwrap();
+    let health: HealthResponse =
+        from_json(query(deps.as_ref(), env, QueryMsg::Health {}).unwrap()).unwrap();
+    assert_eq!(
+        health.last_check.unwrap().undercollateralized_assets,
+        Vec::<String>::new()
+    );
+}
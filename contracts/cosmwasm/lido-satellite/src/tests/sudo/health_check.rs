@@ -0,0 +1,100 @@
+use crate::{
+    contract::{execute, query, sudo},
+    msg::{ExecuteMsg, HealthResponse, QueryMsg, SudoMsg},
+    state::{HealthCheck, IDEMPOTENCY_KEYS},
+    tests::helpers::{
+        instantiate_wrapper, register_asset_wrapper, VALID_IBC_DENOM, VALID_IBC_DENOM_2,
+    },
+};
+use cosmwasm_std::{attr, coin, from_json, testing::mock_info};
+
+#[test]
+fn no_checks_yet() {
+    let (_result, deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    let response: HealthResponse =
+        from_json(query(deps.as_ref(), env, QueryMsg::Health {}).unwrap()).unwrap();
+    assert_eq!(response, HealthResponse { last_check: None });
+}
+
+#[test]
+fn healthy() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    deps.querier
+        .base
+        .update_balance(&env.contract.address, vec![coin(100, VALID_IBC_DENOM)]);
+    deps.querier
+        .base
+        .update_balance("holder", vec![coin(100, "eth")]);
+
+    let response = sudo(deps.as_mut(), env.clone(), SudoMsg::HealthCheck {}).unwrap();
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "health_check"),
+            attr("healthy", "true"),
+            attr("pruned_idempotency_keys", "0"),
+        ]
+    );
+
+    let response: HealthResponse =
+        from_json(query(deps.as_ref(), env.clone(), QueryMsg::Health {}).unwrap()).unwrap();
+    assert_eq!(
+        response,
+        HealthResponse {
+            last_check: Some(HealthCheck {
+                height: env.block.height,
+                time: env.block.time,
+                undercollateralized_assets: vec![],
+                pruned_idempotency_keys: 0,
+            }),
+        }
+    );
+}
+
+#[test]
+fn undercollateralized() {
+    let (_result, mut deps, env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    register_asset_wrapper(deps.as_mut(), env.clone(), "steth", &[VALID_IBC_DENOM_2]);
+    deps.querier.base.update_balance(
+        &env.contract.address,
+        vec![coin(100, VALID_IBC_DENOM), coin(50, VALID_IBC_DENOM_2)],
+    );
+    deps.querier
+        .base
+        .update_balance("holder", vec![coin(100, "eth"), coin(60, "steth")]);
+
+    let response = sudo(deps.as_mut(), env, SudoMsg::HealthCheck {}).unwrap();
+    assert_eq!(response.attributes[1], attr("healthy", "false"));
+    assert_eq!(
+        response.attributes[2],
+        attr("undercollateralized_assets", "steth")
+    );
+}
+
+#[test]
+fn prunes_expired_idempotency_keys() {
+    let (_result, mut deps, mut env) = instantiate_wrapper(VALID_IBC_DENOM, "eth");
+    for key in ["command-1", "command-2"] {
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("relayer", &[coin(10, VALID_IBC_DENOM)]),
+            ExecuteMsg::Mint {
+                receiver: None,
+                idempotency_key: Some(key.to_string()),
+                source_chain: None,
+                source_address: None,
+                evm_sender: None,
+                ibc_hooks_origin: None,
+                referral: None,
+                memo: None,
+            },
+        )
+        .unwrap();
+    }
+
+    env.block.time = env.block.time.plus_days(8);
+    let response = sudo(deps.as_mut(), env, SudoMsg::HealthCheck {}).unwrap();
+    assert_eq!(response.attributes[2], attr("pruned_idempotency_keys", "2"));
+    assert!(IDEMPOTENCY_KEYS.is_empty(deps.as_ref().storage));
+}
@@ -0,0 +1 @@
+mod health_check;
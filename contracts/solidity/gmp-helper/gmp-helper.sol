@@ -7,6 +7,7 @@ import { IAxelarGateway } from "@axelar-network/axelar-gmp-sdk-solidity/contract
 import { IAxelarGasService } from "@axelar-network/axelar-gmp-sdk-solidity/contracts/interfaces/IAxelarGasService.sol";
 import { IERC20 } from "@openzeppelin/contracts/token/ERC20/IERC20.sol";
 import { IERC20Permit } from "@openzeppelin/contracts/token/ERC20/extensions/IERC20Permit.sol";
+import { Strings } from "@openzeppelin/contracts/utils/Strings.sol";
 
 interface IWSTETH is IERC20, IERC20Permit {}
 
@@ -93,8 +94,10 @@ contract GmpHelper {
         WST_ETH.transferFrom(msg.sender, address(this), amount);
         WST_ETH.approve(address(GATEWAY), amount);
 
-        // 2. Generate GMP payload
-        bytes memory payload = _encodeGmpPayload(receiver);
+        // 2. Generate GMP payload. Axelar reports this contract as the source of the call,
+        // so the caller is passed along: Lido Satellite lets it claim funds sent to an
+        // invalid receiver.
+        bytes memory payload = _encodeGmpPayload(receiver, msg.sender);
 
         // 3. Pay for gas
         GAS_SERVICE.payNativeGasForContractCallWithToken{value: msg.value}(
@@ -118,7 +121,8 @@ contract GmpHelper {
     }
 
     function _encodeGmpPayload(
-        string memory targetReceiver
+        string memory targetReceiver,
+        address evmSender
     ) internal pure returns (bytes memory) {
         require(bytes(targetReceiver).length > 8, "receiver address is too short"); // len("neutron1") == 8
         require(bytes(targetReceiver).length <= 256, "receiver address is too long");
@@ -129,14 +133,17 @@ contract GmpHelper {
         }
 
         bytes memory argValues = abi.encode(
-            targetReceiver
+            targetReceiver,
+            Strings.toHexString(evmSender)
         );
 
-        string[] memory argumentNameArray = new string[](1);
+        string[] memory argumentNameArray = new string[](2);
         argumentNameArray[0] = "receiver";
+        argumentNameArray[1] = "evm_sender";
 
-        string[] memory abiTypeArray = new string[](1);
+        string[] memory abiTypeArray = new string[](2);
         abiTypeArray[0] = "string";
+        abiTypeArray[1] = "string";
 
         bytes memory gmpPayload;
         gmpPayload = abi.encode(